@@ -3,10 +3,34 @@
 pub const GRID_SIZE: usize = 5;
 pub const TILE_COUNT: usize = GRID_SIZE * GRID_SIZE;
 
+// Farm expansion: grids grow one row/column at a time up to 8x8
+pub const MAX_GRID_SIZE: u8 = 8;
+pub const EXPANSION_COIN_COST: u64 = 500;   // Per expansion step beyond 5x5
+pub const EXPANSION_WOOD_COST: u16 = 20;
+pub const EXPANSION_STONE_COST: u16 = 10;
+
+// Extra fields: field 0 is the home farm on PlayerAccount, ids 1..=MAX_FIELDS are Field PDAs
+pub const HOME_FIELD_ID: u8 = 0;
+pub const MAX_FIELDS: u8 = 3;
+pub const FIELD_BASE_COST: u64 = 1000;      // Field n costs n × this many coins
+
+// Crop types are numbered 1..=CROP_TYPE_COUNT (0 = empty tile)
+pub const CROP_TYPE_COUNT: usize = 5;
+
 // Fertility settings
 pub const MAX_FERTILITY: u8 = 100;
 pub const MIN_FERTILITY: u8 = 20;
 
+// Soil profile (nitrogen, phosphorus, potassium, moisture retention are 0-100 levels)
+pub const MAX_SOIL_LEVEL: u8 = 100;
+pub const DEFAULT_SOIL_NUTRIENT: u8 = 60;
+pub const DEFAULT_SOIL_MOISTURE_RETENTION: u8 = 50;
+
+// Fertilizer burn: too much load within one window over-fertilizes the tile
+pub const FERTILIZER_BURN_WINDOW: i64 = 86400;
+pub const FERTILIZER_BURN_THRESHOLD: u8 = 4;
+pub const FERTILIZER_BURN_PENALTY_PERCENT: u32 = 30;  // Yield lost at the next harvest
+
 // Starting fertility for new players (kept at 80 to preserve current gameplay)
 pub const DEFAULT_PLAYER_FERTILITY: u8 = 80;
 // Default fertility for migrated/empty tiles
@@ -16,12 +40,86 @@ pub const DEFAULT_MIGRATED_FERTILITY: u8 = 60;
 pub const DEFAULT_BASE_FERTILITY: u8 = 100;
 pub const DEFAULT_ROTATION_BONUS: u8 = 10;
 pub const DEFAULT_SEASON_LENGTH: i64 = 0;
+pub const DEFAULT_RUSH_COINS_PER_MINUTE: u64 = 2;
+pub const GAME_CONFIG_VERSION: u8 = 5;
+
+// Multi-season rotation (per-tile crop history)
+pub const ROTATION_HISTORY_LEN: usize = 4;           // Past plantings remembered per tile
+pub const ROTATION_CYCLE_BONUS_PERCENT: i16 = 15;    // Legume → grain → root on the same tile
+pub const REPEAT_CROP_STREAK: usize = 3;             // Same crop this many times in a row...
+pub const REPEAT_CROP_PENALTY_PERCENT: i16 = -20;    // ...costs this much yield
+
+// Nitrogen fixing: restorative crops leave a one-time soil nitrogen boost for the next non-restorative crop
+pub const NITROGEN_BOOST_TILE: u8 = 15;      // On the harvested tile
+pub const NITROGEN_BOOST_ADJACENT: u8 = 8;   // On each cardinal neighbour
+
+// Marketplace fees (basis points, 250 = 2.5%)
+pub const DEFAULT_MARKETPLACE_FEE_BPS: u16 = 250;
+pub const MAX_MARKETPLACE_FEE_BPS: u16 = 1000;
+pub const BPS_DENOMINATOR: u64 = 10_000;
 
 // Fallow restore rate: 1 fertility per hour
 pub const FALLOW_RESTORE_RATE: i64 = 3600;
+// Fallow regeneration stops once fertility reaches this cap (default for GameConfig)
+pub const DEFAULT_FALLOW_REGEN_CAP: u8 = 80;
+
+// Compost bins: inputs become fill; every COMPOST_FILL_PER_UNIT fill composts into one unit
+pub const MAX_COMPOST_BINS: usize = 4;               // Most bins a player can hold (one state slot each)
+pub const COMPOST_BIN_CAPACITY: u16 = 40;            // Max fill per bin
+pub const COMPOST_BIN_STORAGE_CAP: u16 = 5;          // Finished compost a bin holds before it stops
+pub const COMPOST_FILL_PER_UNIT: u16 = 10;
+pub const COMPOST_SECONDS_PER_UNIT: i64 = 86400;     // One unit per bin per day
+pub const COMPOST_FILL_PER_WASTE: u16 = 2;           // Fill from one unit of crop waste
+pub const COMPOST_FILL_PER_FIBER: u16 = 1;           // Fill from one fiber
+pub const CROP_WASTE_PER_HARVEST: u16 = 1;
+pub const MAX_CROP_WASTE: u16 = 500;
+
+// Resource nodes
+pub const RESOURCE_NODE_COST: u64 = 200;             // Coins to plant a node on your own land
+pub const WORLD_NODE_CAPACITY_MULTIPLIER: u16 = 3;   // Shared world nodes hold this many times more
+
+// Tool durability lost per watering or gather action
+pub const TOOL_DURABILITY_PER_USE: u16 = 1;
+
+// Recipe registry: one slot per item id; unlocks are a per-player bitmask
+pub const MAX_RECIPES: usize = 16;
+pub const MAX_RECIPE_INPUTS: usize = 3;
+pub const MAX_RECIPE_BYPRODUCTS: usize = 2;
+
+// Item-stack inventory: one stack per item id, with room for future items
+pub const INVENTORY_SLOTS: usize = 20;
+
+// Player progression: reaching level L takes XP_LEVEL_STEP × L(L-1)/2 total XP
+pub const MAX_LEVEL: u8 = 30;
+pub const XP_LEVEL_STEP: u64 = 100;
+pub const LEVEL_UP_COIN_REWARD: u64 = 25;     // × the new level
+pub const XP_PER_HARVEST: u64 = 10;
+pub const XP_PER_YIELD_DIVISOR: u64 = 10;     // +1 XP per this many coins harvested
+pub const XP_PER_PATTERN: u64 = 5;            // Per pattern active at harvest
+pub const XP_PER_CRAFT: u64 = 15;
+pub const XP_PER_GATHERED: u64 = 1;           // Per resource unit gathered
+
+// Achievements
+pub const ACHIEVEMENT_WHEAT_TARGET: u16 = 100;   // Wheat harvests for the wheat badge
+
+// Quest board: refreshed from SeasonState.days_passed
+pub const DAILY_QUESTS: usize = 3;
+pub const WEEKLY_QUESTS: usize = 1;
+pub const QUEST_WEEK_DAYS: u32 = 7;
+pub const QUEST_DAILY_COINS: u64 = 50;
+pub const QUEST_DAILY_XP: u64 = 25;
+pub const QUEST_WEEKLY_COINS: u64 = 300;
+pub const QUEST_WEEKLY_XP: u64 = 150;
+
+// Season leaderboard: top players by harvest coins per season and year
+pub const LEADERBOARD_SIZE: usize = 10;
+pub const SEASON_PRIZE_SHARES_BPS: [u16; LEADERBOARD_SIZE] = [3000, 2000, 1500, 1000, 800, 600, 400, 300, 250, 150];
+
+// Trade offers can stay open for at most 7 days
+pub const MAX_TRADE_DURATION: i64 = 7 * 86400;
 
 // Account versioning
-pub const PLAYER_ACCOUNT_VERSION: u8 = 1;
+pub const PLAYER_ACCOUNT_VERSION: u8 = 2;
 
 // Seasons
 pub const NUM_SEASONS: u8 = 4;
@@ -32,6 +130,14 @@ pub const SEASON_LENGTHS: [u32; 4] = [30, 30, 30, 30];
 pub const PLAYER_SEED: &[u8] = b"player";
 pub const GAME_CONFIG_SEED: &[u8] = b"game_config";
 pub const SEASON_STATE_SEED: &[u8] = b"season_state";
+pub const CRAFTED_ASSET_SEED: &[u8] = b"crafted_asset";
+pub const TRADE_OFFER_SEED: &[u8] = b"trade_offer";
+pub const MARKETPLACE_SEED: &[u8] = b"marketplace";
+pub const LISTING_SEED: &[u8] = b"listing";
+pub const FIELD_SEED: &[u8] = b"field";
+pub const RESOURCE_NODE_SEED: &[u8] = b"resource_node";
+pub const RECIPE_REGISTRY_SEED: &[u8] = b"recipe_registry";
+pub const SEASON_LEADERBOARD_SEED: &[u8] = b"season_leaderboard";
 
 // Pattern detection constants
 pub const MIN_ROW_LENGTH: usize = 3;  // Minimum for monoculture row
@@ -42,8 +148,21 @@ pub const MONOCULTURE_ROW_BONUS: f32 = 1.15;
 pub const MONOCULTURE_BLOCK_BONUS: f32 = 1.20;
 pub const COMPANION_WHEAT_CARROT_BONUS: f32 = 1.10;
 pub const COMPANION_CORN_LETTUCE_BONUS: f32 = 1.05;
+// Companion effects from all four neighbours stack, clamped to this range (percent)
+pub const COMPANION_MAX_BONUS_PERCENT: i16 = 20;
+pub const COMPANION_MAX_PENALTY_PERCENT: i16 = -20;
+pub const COMPANION_MAX_WATER_BONUS: u8 = 10;
 pub const CROP_DIVERSITY_BONUS: f32 = 1.25;
 pub const CROSS_PATTERN_BONUS: f32 = 1.30;
 pub const CHECKERBOARD_BONUS: f32 = 1.10;
 pub const PERIMETER_DEFENSE_BONUS: f32 = 1.40;
 pub const ROTATION_SEQUENCE_BONUS: f32 = 1.20;
+pub const DIAGONAL_LINE_BONUS: f32 = 1.15;
+pub const FULL_BLOCK_BONUS: f32 = 1.35;
+pub const RING_BONUS: f32 = 1.25;
+pub const FULL_LINE_BONUS: f32 = 1.25;
+pub const MIRROR_SYMMETRY_BONUS: f32 = 1.15;
+
+// Pattern stacking rules (see StackingRules in state/synergy.rs); GameConfig can override them
+pub const DEFAULT_PATTERN_YIELD_CAP_BPS: u16 = 16_000;   // Per-tile cap after all patterns (1.60x)
+pub const DEFAULT_PATTERN_DIMINISHING_BPS: u16 = 5_000;  // Each further pattern keeps this share of the previous one's weight
@@ -30,7 +30,7 @@ pub enum FarmingError {
     InsufficientPoints,
     #[msg("Cannot water same plot more than once per hour")]
     WateringTooFrequent,
-    #[msg("Plot index must be between 0 and 24")]
+    #[msg("Plot index is outside the farm grid")]
     InvalidPlotIndex,
     
     // Crafting & resources errors
@@ -58,4 +58,158 @@ pub enum FarmingError {
     InvalidPatternType,
     #[msg("Plot position out of grid bounds")]
     PlotOutOfBounds,
+
+    // Farm expansion errors
+    #[msg("Farm is already at the maximum grid size")]
+    MaxGridSizeReached,
+
+    // Field errors
+    #[msg("Maximum number of fields already owned")]
+    MaxFieldsReached,
+    #[msg("Fields must be bought in order (next id is fields_owned + 1)")]
+    InvalidFieldId,
+
+    // Crafted asset errors
+    #[msg("This item cannot be exported as an asset")]
+    ItemNotExportable,
+    #[msg("Not enough crafted items in inventory")]
+    InsufficientCraftedItems,
+    #[msg("Signer does not own this asset")]
+    NotAssetOwner,
+
+    // Trading errors
+    #[msg("Trade must offer and request something")]
+    EmptyTrade,
+    #[msg("Trade duration must be between 1 second and 7 days")]
+    InvalidTradeDuration,
+    #[msg("Trade offer has expired")]
+    TradeExpired,
+    #[msg("Trade offer has not expired yet")]
+    TradeNotExpired,
+    #[msg("This trade offer is reserved for another player")]
+    TradeNotForSigner,
+    #[msg("Cannot accept your own trade offer")]
+    CannotTradeWithSelf,
+    #[msg("Trade offer was cancelled")]
+    TradeCancelled,
+
+    // Marketplace errors
+    #[msg("Listing quantity must be greater than zero")]
+    InvalidListingQuantity,
+    #[msg("Listing price must be greater than zero")]
+    InvalidListingPrice,
+    #[msg("Not enough quantity left on this listing")]
+    ListingQuantityExceeded,
+    #[msg("Only the seller can delist this listing")]
+    NotListingSeller,
+    #[msg("Marketplace fee exceeds the maximum allowed")]
+    InvalidMarketplaceFee,
+    #[msg("Only the treasury can withdraw marketplace fees")]
+    UnauthorizedTreasury,
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+
+    // Game config errors
+    #[msg("Fallow interval must be positive and cap at most max fertility")]
+    InvalidFallowConfig,
+    #[msg("Rush rate must be positive")]
+    InvalidRushRate,
+    #[msg("Pattern cap must be at least 1.0x and diminishing factor at most 1.0")]
+    InvalidStackingRules,
+    #[msg("Game config was written by an unknown version")]
+    UnsupportedConfigVersion,
+    #[msg("Only the game authority can migrate the config")]
+    UnauthorizedConfigMigration,
+    #[msg("Already holding the maximum number of compost bins")]
+    TooManyCompostBins,
+    #[msg("Signer does not own this player account")]
+    NotPlayerOwner,
+    #[msg("Not enough marketplace fees collected")]
+    InsufficientMarketplaceFees,
+    #[msg("Season state has an unknown layout")]
+    UnsupportedSeasonLayout,
+    #[msg("Only the season authority can migrate the season state")]
+    UnauthorizedSeasonMigration,
+
+    // Fertilizer errors
+    #[msg("Invalid fertilizer type")]
+    InvalidFertilizerType,
+    #[msg("Not enough compost in inventory")]
+    InsufficientCompost,
+
+    // Compost bin errors
+    #[msg("No compost bins built")]
+    NoCompostBins,
+    #[msg("Compost bin index out of range")]
+    InvalidCompostBin,
+    #[msg("Not enough room in the compost bin")]
+    CompostBinFull,
+    #[msg("Must add crop waste or fiber")]
+    EmptyCompostInput,
+    #[msg("Not enough crop waste")]
+    InsufficientCropWaste,
+
+    // Resource node errors
+    #[msg("Invalid resource node kind")]
+    InvalidNodeKind,
+    #[msg("Resource node is depleted; wait for it to regrow")]
+    ResourceNodeDepleted,
+    #[msg("This resource node belongs to another player")]
+    NotNodeOwner,
+    #[msg("Only the game authority can create shared world nodes")]
+    UnauthorizedWorldNode,
+
+    // Tool upgrade errors
+    #[msg("Invalid tool kind")]
+    InvalidToolKind,
+    #[msg("Tool is broken; repair it first")]
+    ToolBroken,
+    #[msg("Tool is already at the highest tier")]
+    ToolMaxTier,
+    #[msg("Tool is already at full durability")]
+    ToolAtFullDurability,
+
+    // Recipe registry errors
+    #[msg("Recipe is disabled")]
+    RecipeDisabled,
+    #[msg("Recipe has not been unlocked")]
+    RecipeLocked,
+    #[msg("Recipe is already unlocked")]
+    RecipeAlreadyUnlocked,
+    #[msg("Recipe prerequisites not met")]
+    RecipePrerequisitesNotMet,
+    #[msg("Invalid recipe definition")]
+    InvalidRecipe,
+
+    // Inventory errors
+    #[msg("Invalid item ID")]
+    InvalidItemId,
+    #[msg("No free inventory slot for this item")]
+    InventoryFull,
+
+    // Crafting queue errors
+    #[msg("Crafting is already complete; claim it instead")]
+    CraftingAlreadyComplete,
+
+    // Progression errors
+    #[msg("Player level too low")]
+    LevelTooLow,
+
+    // Quest errors
+    #[msg("Quest index out of range")]
+    InvalidQuestIndex,
+    #[msg("Quest is not complete yet")]
+    QuestNotComplete,
+    #[msg("Quest reward already claimed")]
+    QuestAlreadyClaimed,
+
+    // Season leaderboard errors
+    #[msg("Season leaderboard is frozen")]
+    LeaderboardFrozen,
+    #[msg("Season is still running")]
+    SeasonNotFinished,
+    #[msg("Player did not place on this leaderboard")]
+    NotOnLeaderboard,
+    #[msg("Season prize already claimed")]
+    SeasonPrizeAlreadyClaimed,
 }
@@ -1,9 +1,11 @@
 use anchor_lang::prelude::*;
 use crate::state::synergy::PatternType;
+use crate::state::trade::TradeTerms;
 
 #[event]
 pub struct WaterApplied {
     pub player: Pubkey,
+    pub field_id: u8,
     pub plot_index: u8,
     pub new_water_level: u8,
     pub timestamp: i64,
@@ -12,8 +14,15 @@ pub struct WaterApplied {
 #[event]
 pub struct FertilizerApplied {
     pub player: Pubkey,
+    pub field_id: u8,
     pub plot_index: u8,
     pub new_fertility: u8,
+    pub fertilizer_type: u8,
+    pub boost: u8,            // Temporary fertility now active
+    pub boost_until: i64,
+    pub burn_load: u8,        // Load in the current burn window
+    pub burned: bool,         // Tile is over-fertilized
+    pub burned_now: bool,     // This application caused the burn
 }
 
 #[event]
@@ -32,12 +41,39 @@ pub struct ToolPurchased {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct ToolUpgraded {
+    pub player: Pubkey,
+    pub tool_kind: u8,
+    pub tier: u8,
+    pub durability: u16,
+}
+
+#[event]
+pub struct ToolRepaired {
+    pub player: Pubkey,
+    pub tool_kind: u8,
+    pub tier: u8,
+    pub durability: u16,
+}
+
 #[event]
 pub struct ResourceGathered {
     pub player: Pubkey,
+    pub node: Pubkey,
     pub resource_type: u8,
     pub amount: u16,
     pub new_total: u16,
+    pub node_remaining: u16,
+}
+
+#[event]
+pub struct ResourceNodeCreated {
+    pub node: Pubkey,
+    pub region: Pubkey,     // Owning player, or default for the shared world
+    pub node_id: u32,
+    pub kind: u8,
+    pub capacity: u16,
 }
 
 #[event]
@@ -47,34 +83,286 @@ pub struct ItemCrafted {
     pub instant: bool,
 }
 
+#[event]
+pub struct RecipeUpdated {
+    pub item_id: u8,
+    pub enabled: bool,
+}
+
+#[event]
+pub struct RecipeUnlocked {
+    pub player: Pubkey,
+    pub item_id: u8,
+}
+
+#[event]
+pub struct LevelUp {
+    pub player: Pubkey,
+    pub level: u8,
+    pub xp: u64,
+    pub coin_reward: u64,
+    pub unlocked_crops: Vec<u8>,
+    pub unlocked_recipes: Vec<u8>,
+    pub unlocked_tool_tiers: Vec<u8>,
+    pub unlocked_grid_size: Option<u8>,
+    pub unlocked_field_id: Option<u8>,
+}
+
+#[event]
+pub struct AchievementUnlocked {
+    pub player: Pubkey,
+    pub achievement: u8,
+    pub coin_reward: u64,
+    pub item_reward: Option<u8>,    // ItemId credited, if any
+    pub item_amount: u16,           // Amount credited (capped by the stack limit)
+}
+
+#[event]
+pub struct QuestCompleted {
+    pub player: Pubkey,
+    pub day: u32,
+    pub quest_index: u8,     // Daily slots first, then weekly
+    pub kind: u8,
+    pub target: u8,
+}
+
+#[event]
+pub struct QuestClaimed {
+    pub player: Pubkey,
+    pub quest_index: u8,
+    pub coin_reward: u64,
+    pub xp_reward: u64,
+}
+
+#[event]
+pub struct LeaderboardUpdated {
+    pub player: Pubkey,
+    pub season: u8,
+    pub year: u32,
+    pub coins: u64,           // Player's harvest coins this season
+    pub rank: u8,             // 0 = first place
+}
+
+#[event]
+pub struct SeasonLeaderboardFrozen {
+    pub season: u8,
+    pub year: u32,
+    pub winner: Pubkey,
+    pub winning_coins: u64,
+}
+
+#[event]
+pub struct SeasonPrizePoolFunded {
+    pub season: u8,
+    pub year: u32,
+    pub amount: u64,
+    pub prize_pool: u64,
+}
+
+#[event]
+pub struct SeasonPrizeClaimed {
+    pub player: Pubkey,
+    pub season: u8,
+    pub year: u32,
+    pub rank: u8,
+    pub prize: u64,
+}
+
 #[event]
 pub struct CraftingCompleted {
     pub player: Pubkey,
     pub item_id: u8,
     pub quantity: u16,
+    pub rushed: bool,         // Finished early by paying coins
+    pub coins_spent: u64,     // Rush cost (0 when claimed after waiting)
 }
 
 #[event]
 pub struct CompostCollected {
     pub player: Pubkey,
-    pub fertilizer_gained: u16,
-    pub days_elapsed: u32,
+    pub compost_gained: u16,
+    pub bins: u8,
+}
+
+#[event]
+pub struct CompostBinFilled {
+    pub player: Pubkey,
+    pub bin_index: u8,
+    pub crop_waste_used: u16,
+    pub fiber_used: u16,
+    pub fill: u16,
 }
 
 #[event]
 pub struct PatternDetected {
     pub player: Pubkey,
+    pub field_id: u8,
     pub plot_index: u8,
     pub pattern_type: PatternType,
+    pub tiles: u64,
     pub yield_multiplier: f32,
     pub fertility_bonus: u8,
     pub water_bonus: u8,
 }
 
+#[event]
+pub struct NitrogenBoostConsumed {
+    pub player: Pubkey,
+    pub field_id: u8,
+    pub plot_index: u8,
+    pub crop_type: u8,
+    pub nitrogen_gained: u8,
+    pub soil_nitrogen: u8,
+}
+
+/// Every factor that went into a harvest, in the order they were applied
+#[event]
+pub struct HarvestBreakdown {
+    pub player: Pubkey,
+    pub field_id: u8,
+    pub plot_index: u8,
+    pub crop_type: u8,
+    pub base_yield: u32,          // After timing, soil and fertilizer burn
+    pub season_modifier: f32,
+    pub water_modifier: f32,
+    pub fertilizer_boost: u8,     // Temporary fertility from active fertilizer
+    pub fertilizer_burned: bool,  // Over-fertilization penalty applied
+    pub pattern_multiplier: f32,  // Stacked patterns and companions
+    pub rotation_percent: i16,    // Multi-season rotation bonus or penalty
+    pub rotation_cycle_completed: bool,
+    pub repeat_streak: u8,
+    pub final_yield: u32,
+}
+
+/// One pattern found by a preview or whole-grid scan; `tiles` is a bitmask of tile indices
+#[event]
+pub struct PatternMatchFound {
+    pub player: Pubkey,
+    pub field_id: u8,
+    pub anchor: u8,
+    pub pattern_type: PatternType,
+    pub tiles: u64,
+}
+
 #[event]
 pub struct PatternsPreview {
     pub player: Pubkey,
+    pub field_id: u8,
     pub plot_index: u8,
     pub pattern_count: u8,
     pub total_yield_multiplier: f32,
+    pub companion_yield_percent: i16,
+    pub beneficial_neighbors: u8,
+    pub antagonist_neighbors: u8,
+}
+
+#[event]
+pub struct CraftedAssetExported {
+    pub player: Pubkey,
+    pub asset: Pubkey,
+    pub asset_id: u64,
+    pub item_id: u8,
+}
+
+#[event]
+pub struct CraftedAssetTransferred {
+    pub asset: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub item_id: u8,
+}
+
+#[event]
+pub struct CraftedAssetImported {
+    pub player: Pubkey,
+    pub asset: Pubkey,
+    pub item_id: u8,
+}
+
+#[event]
+pub struct TradeCreated {
+    pub maker: Pubkey,
+    pub trade_offer: Pubkey,
+    pub offer_id: u64,
+    pub taker: Option<Pubkey>,
+    pub offered: TradeTerms,
+    pub requested: TradeTerms,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct TradeAccepted {
+    pub maker: Pubkey,
+    pub taker: Pubkey,
+    pub trade_offer: Pubkey,
+    pub offer_id: u64,
+    pub offered: TradeTerms,
+    pub requested: TradeTerms,
+}
+
+#[event]
+pub struct TradeCancelled {
+    pub maker: Pubkey,
+    pub trade_offer: Pubkey,
+    pub offer_id: u64,
+    pub expired: bool,
+    pub fully_refunded: bool,         // False while part of the escrow waits for stack space
+}
+
+#[event]
+pub struct ListingCreated {
+    pub listing: Pubkey,
+    pub listing_id: u64,
+    pub seller: Pubkey,
+    pub resource_type: u8,
+    pub quantity: u16,
+    pub price_per_unit: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ListingPurchased {
+    pub listing: Pubkey,
+    pub listing_id: u64,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub resource_type: u8,
+    pub quantity: u16,
+    pub price_per_unit: u64,
+    pub fee: u64,
+    pub remaining: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ListingDelisted {
+    pub listing: Pubkey,
+    pub listing_id: u64,
+    pub seller: Pubkey,
+    pub resource_type: u8,
+    pub quantity_returned: u16,
+    pub remaining: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketplaceFeesWithdrawn {
+    pub treasury: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct FarmExpanded {
+    pub player: Pubkey,
+    pub field_id: u8,
+    pub new_grid_size: u8,
+    pub coins_spent: u64,
+}
+
+#[event]
+pub struct FieldPurchased {
+    pub player: Pubkey,
+    pub field: Pubkey,
+    pub field_id: u8,
+    pub coins_spent: u64,
 }
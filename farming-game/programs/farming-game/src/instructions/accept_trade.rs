@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{PLAYER_SEED, TRADE_OFFER_SEED};
+use crate::errors::FarmingError;
+use crate::events::TradeAccepted;
+use crate::state::{PlayerAccount, TradeOffer};
+
+#[derive(Accounts)]
+pub struct AcceptTrade<'info> {
+    /// Closed on accept; rent goes back to the maker
+    #[account(
+        mut,
+        close = maker,
+        seeds = [TRADE_OFFER_SEED, trade_offer.maker.as_ref(), &trade_offer.offer_id.to_le_bytes()],
+        bump = trade_offer.bump
+    )]
+    pub trade_offer: Account<'info, TradeOffer>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, trade_offer.maker.as_ref()],
+        bump = maker_player_account.bump
+    )]
+    pub maker_player_account: Account<'info, PlayerAccount>,
+
+    /// CHECK: Only receives the escrow rent; address is pinned to the offer's maker
+    #[account(mut, address = trade_offer.maker)]
+    pub maker: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, authority.key().as_ref()],
+        bump = player_account.bump
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+/// Settle a trade: the taker pays the requested side and receives the escrow.
+/// Either both player accounts are updated or the whole instruction fails.
+pub fn handler(ctx: Context<AcceptTrade>) -> Result<()> {
+    let trade_offer = &ctx.accounts.trade_offer;
+    let taker = ctx.accounts.authority.key();
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(!trade_offer.cancelled, FarmingError::TradeCancelled);
+    require!(!trade_offer.is_expired(current_time), FarmingError::TradeExpired);
+    require!(taker != trade_offer.maker, FarmingError::CannotTradeWithSelf);
+    if let Some(reserved_for) = trade_offer.taker {
+        require!(reserved_for == taker, FarmingError::TradeNotForSigner);
+    }
+
+    let offered = trade_offer.offered;
+    let requested = trade_offer.requested;
+
+    // Taker pays the requested side and receives the escrowed side
+    let taker_account = &mut ctx.accounts.player_account;
+    require!(taker_account.coins >= requested.coins, FarmingError::InsufficientPoints);
+    taker_account.consume_resources(&requested.resource_list())?;
+    taker_account.coins -= requested.coins;
+    taker_account.add_resources(&offered.resource_list())?;
+    taker_account.coins = taker_account.coins.saturating_add(offered.coins);
+
+    // Maker receives the requested side
+    let maker_account = &mut ctx.accounts.maker_player_account;
+    maker_account.add_resources(&requested.resource_list())?;
+    maker_account.coins = maker_account.coins.saturating_add(requested.coins);
+
+    emit!(TradeAccepted {
+        maker: trade_offer.maker,
+        taker,
+        trade_offer: trade_offer.key(),
+        offer_id: trade_offer.offer_id,
+        offered,
+        requested,
+    });
+
+    msg!("Trade offer {} accepted by {}", trade_offer.offer_id, taker);
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::{NUM_SEASONS, SEASON_STATE_SEED};
-use crate::state::SeasonState;
+use crate::constants::{SEASON_LEADERBOARD_SEED, SEASON_STATE_SEED};
+use crate::state::{advance_season_day, SeasonLeaderboard, SeasonState};
 
 #[derive(Accounts)]
 pub struct AdvanceDay<'info> {
@@ -11,20 +11,42 @@ pub struct AdvanceDay<'info> {
         bump
     )]
     pub season_state: Account<'info, SeasonState>,
+
+    /// Leaderboard of the season in progress; frozen if this day ends it
+    #[account(
+        mut,
+        seeds = [SEASON_LEADERBOARD_SEED, &[season_state.current_season], &season_state.year.to_le_bytes()],
+        bump = season_leaderboard.bump
+    )]
+    pub season_leaderboard: Account<'info, SeasonLeaderboard>,
+
+    /// Leaderboard of the following season, opened ahead of the rollover
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = SeasonLeaderboard::SPACE,
+        seeds = [SEASON_LEADERBOARD_SEED, &[season_state.next_season().0], &season_state.next_season().1.to_le_bytes()],
+        bump
+    )]
+    pub next_season_leaderboard: Account<'info, SeasonLeaderboard>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<AdvanceDay>) -> Result<()> {
     let season_state = &mut ctx.accounts.season_state;
 
-    season_state.days_passed = season_state.days_passed.saturating_add(1);
-
-    let days_in_current_season = season_state.days_passed.saturating_sub(season_state.season_start_day);
-    let season_length = season_state.season_length();
-
-    if days_in_current_season >= season_length {
-        season_state.current_season = (season_state.current_season + 1) % NUM_SEASONS;
-        season_state.season_start_day = season_state.days_passed;
-        msg!("Season advanced to {}", season_state.current_season);
+    let rolled_over = advance_season_day(
+        season_state,
+        &mut ctx.accounts.season_leaderboard,
+        &mut ctx.accounts.next_season_leaderboard,
+        ctx.bumps.next_season_leaderboard,
+    );
+    if rolled_over {
+        msg!("Season advanced to {} (year {})", season_state.current_season, season_state.year);
     }
 
     msg!("Day advanced to {}", season_state.days_passed);
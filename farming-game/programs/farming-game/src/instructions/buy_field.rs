@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{FIELD_SEED, GRID_SIZE, MAX_FIELDS, PLAYER_SEED};
+use crate::errors::FarmingError;
+use crate::events::FieldPurchased;
+use crate::state::{field_required_level, Field, PlayerAccount};
+
+#[derive(Accounts)]
+#[instruction(field_id: u8)]
+pub struct BuyField<'info> {
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, authority.key().as_ref()],
+        bump = player_account.bump
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    /// Field PDA with its own 5x5 grid. A field left behind by a closed
+    /// PlayerAccount already exists and is taken over instead of created.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = Field::space(GRID_SIZE as u8),
+        seeds = [FIELD_SEED, authority.key().as_ref(), &[field_id]],
+        bump
+    )]
+    pub field: Account<'info, Field>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Buy an additional field. Fields are numbered 1..=MAX_FIELDS and bought in order.
+pub fn handler(ctx: Context<BuyField>, field_id: u8) -> Result<()> {
+    let player_account = &mut ctx.accounts.player_account;
+
+    require!(player_account.fields_owned < MAX_FIELDS, FarmingError::MaxFieldsReached);
+    require!(field_id == player_account.fields_owned + 1, FarmingError::InvalidFieldId);
+    require!(player_account.level >= field_required_level(field_id), FarmingError::LevelTooLow);
+
+    let cost = Field::purchase_cost(field_id);
+    require!(player_account.coins >= cost, FarmingError::InsufficientPoints);
+    player_account.coins -= cost;
+    player_account.fields_owned = field_id;
+
+    let authority = ctx.accounts.authority.key();
+    let field = &mut ctx.accounts.field;
+    field.open(authority, field_id, ctx.bumps.field);
+
+    emit!(FieldPurchased {
+        player: authority,
+        field: field.key(),
+        field_id,
+        coins_spent: cost,
+    });
+
+    msg!("Field {} purchased for {} coins", field_id, cost);
+    Ok(())
+}
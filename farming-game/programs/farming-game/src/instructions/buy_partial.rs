@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{GAME_CONFIG_SEED, LISTING_SEED, MARKETPLACE_SEED, PLAYER_SEED};
+use crate::errors::FarmingError;
+use crate::events::ListingPurchased;
+use crate::state::{GameConfig, Listing, Marketplace, PlayerAccount};
+
+#[derive(Accounts)]
+pub struct BuyPartial<'info> {
+    #[account(
+        mut,
+        seeds = [MARKETPLACE_SEED],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump = game_config.bump
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [LISTING_SEED, &[listing.resource_type], &listing.listing_id.to_le_bytes()],
+        bump = listing.bump
+    )]
+    pub listing: Account<'info, Listing>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, listing.seller.as_ref()],
+        bump = seller_player_account.bump
+    )]
+    pub seller_player_account: Account<'info, PlayerAccount>,
+
+    /// CHECK: Receives the listing rent once it sells out; address is pinned to the seller
+    #[account(mut, address = listing.seller)]
+    pub seller: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, authority.key().as_ref()],
+        bump = player_account.bump
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+/// Buy some or all of a listing. The marketplace fee is taken from the seller's proceeds.
+pub fn handler(ctx: Context<BuyPartial>, quantity: u16) -> Result<()> {
+    let listing = &ctx.accounts.listing;
+    let buyer = ctx.accounts.authority.key();
+
+    require!(buyer != listing.seller, FarmingError::CannotTradeWithSelf);
+    require!(quantity > 0, FarmingError::InvalidListingQuantity);
+    require!(quantity <= listing.quantity, FarmingError::ListingQuantityExceeded);
+
+    let (total_price, fee) = listing.quote(quantity, ctx.accounts.game_config.marketplace_fee_bps)?;
+
+    // Buyer pays the full price and receives the units
+    let buyer_account = &mut ctx.accounts.player_account;
+    require!(buyer_account.coins >= total_price, FarmingError::InsufficientPoints);
+    buyer_account.coins -= total_price;
+    buyer_account.add_resources(&[(listing.resource_type, quantity)])?;
+
+    // Seller receives the price minus the marketplace fee
+    let seller_account = &mut ctx.accounts.seller_player_account;
+    seller_account.coins = seller_account.coins.saturating_add(total_price - fee);
+
+    let marketplace = &mut ctx.accounts.marketplace;
+    marketplace.fees_collected = marketplace.fees_collected.saturating_add(fee);
+    let volume = &mut marketplace.volume_traded[listing.resource_type as usize];
+    *volume = volume.saturating_add(total_price);
+
+    let listing = &mut ctx.accounts.listing;
+    listing.quantity -= quantity;
+
+    emit!(ListingPurchased {
+        listing: listing.key(),
+        listing_id: listing.listing_id,
+        seller: listing.seller,
+        buyer,
+        resource_type: listing.resource_type,
+        quantity,
+        price_per_unit: listing.price_per_unit,
+        fee,
+        remaining: listing.quantity,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Bought {} units from listing {} for {} coins (fee {})", quantity, listing.listing_id, total_price, fee);
+
+    // Sold out listings are closed and the rent returned to the seller
+    if listing.quantity == 0 {
+        listing.close(ctx.accounts.seller.to_account_info())?;
+    }
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::errors::FarmingError;
-use crate::state::PlayerAccount;
+use crate::state::{ItemId, PlayerAccount};
 use crate::state::tools::{ToolType, ToolConfig};
 use crate::events::ToolPurchased;
 
@@ -46,13 +46,16 @@ pub fn handler(ctx: Context<BuyTool>, tool_type: u8, quantity: u16) -> Result<()
     match tool {
         ToolType::WateringCan => {
             // Watering cans are not stackable (single item with uses)
-            player_account.watering_can_uses = 10;
+            player_account.refill_watering_can();
         }
         ToolType::Fertilizer => {
-            player_account.fertilizer_count = player_account.fertilizer_count.saturating_add(quantity);
+            player_account.inventory.add(ItemId::Fertilizer, quantity)?;
+        }
+        ToolType::PremiumFertilizer => {
+            player_account.inventory.add(ItemId::PremiumFertilizer, quantity)?;
         }
         ToolType::PremiumSeeds => {
-            player_account.premium_seeds = player_account.premium_seeds.saturating_add(quantity);
+            player_account.inventory.add(ItemId::PremiumSeeds, quantity)?;
         }
     }
     
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{PLAYER_SEED, TRADE_OFFER_SEED};
+use crate::errors::FarmingError;
+use crate::events::TradeCancelled;
+use crate::state::{PlayerAccount, TradeOffer};
+
+#[derive(Accounts)]
+pub struct CancelTrade<'info> {
+    /// Closed once the escrow is fully refunded; rent goes back to the maker
+    #[account(
+        mut,
+        seeds = [TRADE_OFFER_SEED, trade_offer.maker.as_ref(), &trade_offer.offer_id.to_le_bytes()],
+        bump = trade_offer.bump
+    )]
+    pub trade_offer: Account<'info, TradeOffer>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, trade_offer.maker.as_ref()],
+        bump = maker_player_account.bump
+    )]
+    pub maker_player_account: Account<'info, PlayerAccount>,
+
+    /// CHECK: Only receives the escrow rent; address is pinned to the offer's maker
+    #[account(mut, address = trade_offer.maker)]
+    pub maker: UncheckedAccount<'info>,
+
+    /// The maker can cancel at any time; anyone can clean up an expired or already cancelled offer
+    pub authority: Signer<'info>,
+}
+
+/// Return the escrowed resources and coins to the maker. Resources that do not fit the
+/// maker's stacks stay escrowed in the cancelled offer until cancel is called again.
+pub fn handler(ctx: Context<CancelTrade>) -> Result<()> {
+    let trade_offer = &mut ctx.accounts.trade_offer;
+    let current_time = Clock::get()?.unix_timestamp;
+    let expired = trade_offer.is_expired(current_time);
+
+    if ctx.accounts.authority.key() != trade_offer.maker && !trade_offer.cancelled {
+        require!(expired, FarmingError::TradeNotExpired);
+    }
+    trade_offer.cancelled = true;
+
+    let maker_account: &mut PlayerAccount = &mut ctx.accounts.maker_player_account;
+    trade_offer.offered.pay_out_capped(&mut maker_account.inventory, &mut maker_account.coins)?;
+    let fully_refunded = trade_offer.offered.is_empty();
+
+    emit!(TradeCancelled {
+        maker: trade_offer.maker,
+        trade_offer: trade_offer.key(),
+        offer_id: trade_offer.offer_id,
+        expired,
+        fully_refunded,
+    });
+
+    msg!("Trade offer {} cancelled (expired: {}, fully refunded: {})", trade_offer.offer_id, expired, fully_refunded);
+    if fully_refunded {
+        trade_offer.close(ctx.accounts.maker.to_account_info())?;
+    }
+    Ok(())
+}
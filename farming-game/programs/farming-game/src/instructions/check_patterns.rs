@@ -1,18 +1,31 @@
 use anchor_lang::prelude::*;
-use crate::state::{PlayerAccount, PatternDetector};
-use crate::events::PatternsPreview;
+use crate::constants::{FIELD_SEED, GAME_CONFIG_SEED, HOME_FIELD_ID, PLAYER_SEED};
+use crate::state::{Field, GameConfig, PlayerAccount, PatternDetector, PatternType};
+use crate::events::{PatternMatchFound, PatternsPreview};
 use crate::errors::FarmingError;
-use crate::state::crop::CropType;
 
 #[derive(Accounts)]
 #[instruction(plot_index: u8)]
 pub struct CheckPatterns<'info> {
     #[account(
-        seeds = [b"player", authority.key().as_ref()],
+        seeds = [PLAYER_SEED, authority.key().as_ref()],
         bump = player_account.bump,
     )]
     pub player_account: Account<'info, PlayerAccount>,
 
+    /// Extra field to act on; omit to use the home farm
+    #[account(
+        seeds = [FIELD_SEED, authority.key().as_ref(), &[field.field_id]],
+        bump = field.bump
+    )]
+    pub field: Option<Account<'info, Field>>,
+
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump = game_config.bump
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
     #[account(signer)]
     pub authority: Signer<'info>,
 }
@@ -20,68 +33,65 @@ pub struct CheckPatterns<'info> {
 /// Handler: Check which patterns exist at a plot without harvesting
 /// This is read-only and useful for UI previews and testing
 pub fn handler(ctx: Context<CheckPatterns>, plot_index: u8) -> Result<()> {
-    require!(plot_index < 25, FarmingError::InvalidPlotIndex);
-
     let player_account = &ctx.accounts.player_account;
+    let (farm, field_id) = match ctx.accounts.field.as_deref() {
+        Some(field) => (&field.farm, field.field_id),
+        None => (&player_account.farm, HOME_FIELD_ID),
+    };
+    require!((plot_index as usize) < farm.tile_count(), FarmingError::InvalidPlotIndex);
+
     let current_time = Clock::get()?.unix_timestamp;
 
     // Convert plot_index to (row, col)
-    let row = (plot_index / 5) as usize;
-    let col = (plot_index % 5) as usize;
+    let (row, col) = farm.tile_coords(plot_index);
 
     // Detect all patterns at this position
     let detected_patterns = PatternDetector::detect_patterns(
-        &player_account.farm_tiles,
+        &farm.tiles,
+        farm.grid_size as usize,
         row,
         col,
         current_time,
     );
 
-    // Calculate total yield multiplier (patterns stack multiplicatively)
-    let mut total_yield_multiplier = 1.0f32;
-    for pattern in detected_patterns.iter() {
-        let bonus = pattern.get_bonus();
-        total_yield_multiplier *= bonus.yield_multiplier;
+    for found in detected_patterns.iter() {
+        emit!(PatternMatchFound {
+            player: player_account.owner,
+            field_id,
+            anchor: found.anchor,
+            pattern_type: found.pattern,
+            tiles: found.tiles,
+        });
     }
 
-    // Check companion planting
-    if let Some(companion_crop_type) = PatternDetector::check_companion_planting(
-        &player_account.farm_tiles,
+    // Check companion planting (all four neighbours, capped)
+    let companion = PatternDetector::check_companion_planting(
+        &farm.tiles,
+        farm.grid_size as usize,
         row,
         col,
         current_time,
-    ) {
-        let tile = &player_account.farm_tiles[plot_index as usize];
-        if tile.crop_type != 0 {
-            if let (Some(center), Some(neighbor)) = (
-                crop_type_from_u8(tile.crop_type),
-                crop_type_from_u8(companion_crop_type),
-            ) {
-                if let Some(companion_bonus) = crate::state::synergy::get_companion_bonus(center, neighbor) {
-                    total_yield_multiplier *= companion_bonus.yield_multiplier;
-                }
-            }
-        }
-    }
+    )
+    .unwrap_or_default();
+
+    // Same stacking rules as harvest_crop, so the preview matches the real payout
+    let patterns: Vec<PatternType> = detected_patterns.iter().map(|m| m.pattern).collect();
+    let total_yield_multiplier = ctx.accounts.game_config
+        .stacking_rules()
+        .stack(&patterns, Some(companion))
+        .yield_multiplier;
 
     // Emit event for UI preview
     emit!(PatternsPreview {
         player: player_account.owner,
+        field_id,
         plot_index,
         pattern_count: detected_patterns.len() as u8,
         total_yield_multiplier,
+        companion_yield_percent: companion.yield_percent,
+        beneficial_neighbors: companion.beneficial_neighbors,
+        antagonist_neighbors: companion.antagonist_neighbors,
     });
 
     Ok(())
 }
-
-fn crop_type_from_u8(value: u8) -> Option<CropType> {
-    match value {
-        1 => Some(CropType::Wheat),
-        2 => Some(CropType::Tomato),
-        3 => Some(CropType::Corn),
-        4 => Some(CropType::Carrot),
-        5 => Some(CropType::Lettuce),
-        _ => None,
-    }
-}
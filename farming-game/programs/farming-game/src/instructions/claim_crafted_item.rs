@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
+use crate::constants::{RECIPE_REGISTRY_SEED, SEASON_STATE_SEED, XP_PER_CRAFT};
 use crate::errors::FarmingError;
-use crate::state::{PlayerAccount, recipes::CraftableItem};
+use crate::state::{award_xp, grant_achievements, track_quest, PlayerAccount, QuestKind, RecipeRegistry, SeasonState, recipes::CraftableItem};
 
 #[derive(Accounts)]
 pub struct ClaimCraftedItem<'info> {
@@ -11,6 +12,18 @@ pub struct ClaimCraftedItem<'info> {
         has_one = owner @ FarmingError::InvalidTileIndex
     )]
     pub player_account: Account<'info, PlayerAccount>,
+
+    #[account(
+        seeds = [RECIPE_REGISTRY_SEED],
+        bump = recipe_registry.bump
+    )]
+    pub recipe_registry: Account<'info, RecipeRegistry>,
+
+    #[account(
+        seeds = [SEASON_STATE_SEED],
+        bump
+    )]
+    pub season_state: Account<'info, SeasonState>,
     
     #[account(signer)]
     pub authority: Signer<'info>,
@@ -31,44 +44,48 @@ pub fn handler(ctx: Context<ClaimCraftedItem>) -> Result<()> {
     // Check if crafting is complete
     require!(player_account.crafting_complete(current_time), FarmingError::CraftingNotComplete);
     
+    finish_crafting_job(
+        player_account,
+        &ctx.accounts.recipe_registry,
+        ctx.accounts.authority.key(),
+        ctx.accounts.season_state.days_passed,
+        0,
+    )
+}
+
+/// Deliver the queued job's outputs and clear the queue. `coins_spent` > 0 marks a rushed job.
+pub(crate) fn finish_crafting_job(
+    player_account: &mut PlayerAccount,
+    recipe_registry: &RecipeRegistry,
+    player: Pubkey,
+    days_passed: u32,
+    coins_spent: u64,
+) -> Result<()> {
     // Get the crafted item
-    let job = player_account.crafting_queue.unwrap();
+    let job = player_account.crafting_queue.ok_or(FarmingError::NoCraftingInProgress)?;
     let item = CraftableItem::from_u8(job.item_id)?;
-    let quantity = item.get_output_quantity();
+    let recipe = recipe_registry.get(job.item_id)?;
+    let quantity = job.output_quantity;
     
-    // Add item to inventory
-    match item {
-        CraftableItem::WateringCanRefill => {
-            player_account.watering_can_uses = 10;
-        }
-        CraftableItem::Fertilizer => {
-            player_account.fertilizer_count = player_account.fertilizer_count.saturating_add(quantity);
-        }
-        CraftableItem::CompostBin => {
-            player_account.compost_bin_count = player_account.compost_bin_count.saturating_add(quantity as u8);
-        }
-        CraftableItem::Scarecrow => {
-            player_account.scarecrow_count = player_account.scarecrow_count.saturating_add(quantity as u8);
-        }
-        CraftableItem::Fence => {
-            player_account.fence_count = player_account.fence_count.saturating_add(quantity as u8);
-        }
-        CraftableItem::Sprinkler => {
-            player_account.sprinkler_count = player_account.sprinkler_count.saturating_add(quantity as u8);
-        }
-        CraftableItem::AdvancedTool => {
-            player_account.advanced_tools = player_account.advanced_tools.saturating_add(quantity as u8);
-        }
-    }
+    // Add item and any byproducts to inventory
+    player_account.add_crafted_item(item, quantity)?;
+    player_account.inventory.add_all(&recipe.byproduct_list())?;
+    player_account.crafts_completed = player_account.crafts_completed.saturating_add(1);
     
     // Clear crafting queue
     player_account.crafting_queue = None;
+    award_xp(player_account, player, XP_PER_CRAFT);
+    let unlocked = player_account.achievements.record_craft(item);
+    grant_achievements(player_account, player, &unlocked);
+    track_quest(player_account, player, days_passed, QuestKind::CraftItem, job.item_id, 1);
     
     // Emit event
     emit!(crate::events::CraftingCompleted {
-        player: ctx.accounts.authority.key(),
+        player,
         item_id: job.item_id,
         quantity,
+        rushed: coins_spent > 0,
+        coins_spent,
     });
     
     Ok(())
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::constants::{PLAYER_SEED, SEASON_STATE_SEED};
+use crate::errors::FarmingError;
+use crate::events::QuestClaimed;
+use crate::state::{claim_quest_reward, PlayerAccount, SeasonState};
+
+#[derive(Accounts)]
+pub struct ClaimQuest<'info> {
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, authority.key().as_ref()],
+        bump = player_account.bump,
+        has_one = owner @ FarmingError::NotPlayerOwner
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    #[account(
+        seeds = [SEASON_STATE_SEED],
+        bump
+    )]
+    pub season_state: Account<'info, SeasonState>,
+    
+    #[account(signer)]
+    pub authority: Signer<'info>,
+    
+    pub owner: Signer<'info>,
+}
+
+/// Pay out a completed quest from today's board (this week's, for weekly quests)
+pub fn handler(ctx: Context<ClaimQuest>, quest_index: u8) -> Result<()> {
+    let player = ctx.accounts.authority.key();
+    let (coin_reward, xp_reward) = claim_quest_reward(
+        &mut ctx.accounts.player_account,
+        player,
+        ctx.accounts.season_state.days_passed,
+        quest_index,
+    )?;
+    
+    emit!(QuestClaimed {
+        player,
+        quest_index,
+        coin_reward,
+        xp_reward,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{PLAYER_SEED, SEASON_LEADERBOARD_SEED, SEASON_STATE_SEED};
+use crate::errors::FarmingError;
+use crate::events::SeasonPrizeClaimed;
+use crate::state::{freeze_leaderboard, PlayerAccount, SeasonLeaderboard, SeasonState};
+
+#[derive(Accounts)]
+pub struct ClaimSeasonPrize<'info> {
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    #[account(
+        seeds = [SEASON_STATE_SEED],
+        bump
+    )]
+    pub season_state: Account<'info, SeasonState>,
+
+    #[account(
+        mut,
+        seeds = [SEASON_LEADERBOARD_SEED, &[season_leaderboard.season], &season_leaderboard.year.to_le_bytes()],
+        bump = season_leaderboard.bump
+    )]
+    pub season_leaderboard: Account<'info, SeasonLeaderboard>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ClaimSeasonPrize>) -> Result<()> {
+    let season_state = &ctx.accounts.season_state;
+    let leaderboard = &mut ctx.accounts.season_leaderboard;
+
+    // A past season's board may not have been frozen if advance_day ran without it
+    if !leaderboard.frozen {
+        let is_current = leaderboard.season == season_state.current_season && leaderboard.year == season_state.year;
+        require!(!is_current, FarmingError::SeasonNotFinished);
+        freeze_leaderboard(leaderboard);
+    }
+
+    let player = ctx.accounts.authority.key();
+    let rank = leaderboard.rank_of(&player).ok_or(FarmingError::NotOnLeaderboard)?;
+    let prize = leaderboard.pay_out(rank)?;
+
+    let player_account = &mut ctx.accounts.player_account;
+    player_account.coins = player_account.coins.saturating_add(prize);
+
+    emit!(SeasonPrizeClaimed {
+        player,
+        season: leaderboard.season,
+        year: leaderboard.year,
+        rank: rank as u8,
+        prize,
+    });
+
+    msg!("Season {} year {} prize: rank {} paid {} coins", leaderboard.season, leaderboard.year, rank + 1, prize);
+    Ok(())
+}
@@ -1,8 +1,8 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::{PLAYER_SEED, TILE_COUNT};
+use crate::constants::{FIELD_SEED, PLAYER_SEED};
 use crate::errors::FarmingError;
-use crate::state::PlayerAccount;
+use crate::state::{select_farm, Field, PlayerAccount};
 
 #[derive(Accounts)]
 pub struct ClearTile<'info> {
@@ -13,21 +13,32 @@ pub struct ClearTile<'info> {
     )]
     pub player_account: Account<'info, PlayerAccount>,
 
+    /// Extra field to act on; omit to use the home farm
+    #[account(
+        mut,
+        seeds = [FIELD_SEED, authority.key().as_ref(), &[field.field_id]],
+        bump = field.bump
+    )]
+    pub field: Option<Account<'info, Field>>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 }
 
 pub fn handler(ctx: Context<ClearTile>, tile_index: u8) -> Result<()> {
-    require!(tile_index < TILE_COUNT as u8, FarmingError::InvalidTileIndex);
-
-    let player_account = &mut ctx.accounts.player_account;
-    let tile = &mut player_account.farm_tiles[tile_index as usize];
+    let player_account: &mut PlayerAccount = &mut ctx.accounts.player_account;
+    let (farm, field_id) = select_farm(&mut player_account.farm, ctx.accounts.field.as_deref_mut());
+    require!((tile_index as usize) < farm.tile_count(), FarmingError::InvalidTileIndex);
+    let tile = &mut farm.tiles[tile_index as usize];
 
+    // Clearing a crop starts the fallow clock; clearing an empty tile keeps it running
+    if tile.crop_type != 0 {
+        tile.fallow_since = Clock::get()?.unix_timestamp;
+    }
     tile.crop_type = 0;
     tile.planted_at = 0;
-    tile.restorative_bonus_used = false;
     tile.planted_in_season = None;
 
-    msg!("Tile {} cleared", tile_index);
+    msg!("Field {} tile {} cleared", field_id, tile_index);
     Ok(())
 }
@@ -19,6 +19,7 @@ pub struct ClosePlayer<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Field PDAs are left in place; buy_field takes them over once the player buys them again
 pub fn handler(ctx: Context<ClosePlayer>) -> Result<()> {
     let account = &ctx.accounts.player_account;
     let authority = &ctx.accounts.authority;
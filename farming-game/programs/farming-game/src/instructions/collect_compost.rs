@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::errors::FarmingError;
-use crate::state::PlayerAccount;
+use crate::state::{ItemId, PlayerAccount};
 
 #[derive(Accounts)]
 pub struct CollectCompost<'info> {
@@ -26,24 +26,23 @@ pub fn handler(ctx: Context<CollectCompost>) -> Result<()> {
     let current_time = clock.unix_timestamp;
     
     // Check if player has any compost bins
-    require!(player_account.compost_bin_count > 0, FarmingError::NoCraftingInProgress);
-    
-    // Calculate days elapsed since last collection
-    let seconds_per_day = 86400i64;
-    let days_elapsed = current_time.saturating_sub(player_account.last_compost_collection) / seconds_per_day;
+    require!(player_account.active_compost_bins() > 0, FarmingError::NoCompostBins);
+    
+    // Settle every bin and empty its storage; partial progress stays in the bin
+    let bins = player_account.active_compost_bins();
+    let mut compost_gained = 0u16;
+    for bin in player_account.compost_bins.iter_mut().take(bins) {
+        bin.update(current_time);
+        compost_gained = compost_gained.saturating_add(bin.take_stored());
+    }
     
-    // Generate fertilizer (1 per compost bin per day)
-    let fertilizer_generated = (player_account.compost_bin_count as u64)
-        .saturating_mul(days_elapsed as u64) as u16;
+    player_account.inventory.add(ItemId::Compost, compost_gained)?;
     
-    if fertilizer_generated > 0 {
-        player_account.fertilizer_count = player_account.fertilizer_count.saturating_add(fertilizer_generated);
-        player_account.last_compost_collection = current_time;
-        
+    if compost_gained > 0 {
         emit!(crate::events::CompostCollected {
             player: ctx.accounts.authority.key(),
-            fertilizer_gained: fertilizer_generated,
-            days_elapsed: days_elapsed as u32,
+            compost_gained,
+            bins: bins as u8,
         });
     }
     
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
+use crate::constants::{RECIPE_REGISTRY_SEED, SEASON_STATE_SEED, XP_PER_CRAFT};
 use crate::errors::FarmingError;
-use crate::state::{PlayerAccount, recipes::CraftableItem};
+use crate::state::{award_xp, grant_achievements, track_quest, PlayerAccount, QuestKind, RecipeRegistry, SeasonState, recipes::CraftableItem};
 
 #[derive(Accounts)]
 #[instruction(item_id: u8)]
@@ -12,6 +13,18 @@ pub struct CraftItem<'info> {
         has_one = owner @ FarmingError::InvalidTileIndex
     )]
     pub player_account: Account<'info, PlayerAccount>,
+
+    #[account(
+        seeds = [RECIPE_REGISTRY_SEED],
+        bump = recipe_registry.bump
+    )]
+    pub recipe_registry: Account<'info, RecipeRegistry>,
+
+    #[account(
+        seeds = [SEASON_STATE_SEED],
+        bump
+    )]
+    pub season_state: Account<'info, SeasonState>,
     
     #[account(signer)]
     pub authority: Signer<'info>,
@@ -26,26 +39,45 @@ pub fn handler(ctx: Context<CraftItem>, item_id: u8) -> Result<()> {
     let clock = &ctx.accounts.clock;
     let current_time = clock.unix_timestamp;
     
-    // Parse craftable item
+    // Parse craftable item and look up its current recipe
     let item = CraftableItem::from_u8(item_id)?;
+    let recipe = *ctx.accounts.recipe_registry.recipe(item_id)?;
+    require!(player_account.level >= recipe.required_level, FarmingError::LevelTooLow);
+    require!(
+        recipe.is_starter() || player_account.has_unlocked_recipe(item_id),
+        FarmingError::RecipeLocked
+    );
     
     // Check if already crafting (unless instant craft allowed)
-    if player_account.is_crafting() && !item.is_instant() {
+    if player_account.is_crafting() && !recipe.is_instant() {
         return Err(FarmingError::CraftingInProgress.into());
     }
     
-    // Get recipe inputs
-    let recipe_inputs = item.get_recipe_inputs();
+    // Make sure the output will fit before taking anything
+    player_account.ensure_room_for(item, recipe.output_quantity)?;
     
     // Consume resources
-    player_account.consume_resources(&recipe_inputs)?;
+    player_account.inventory.remove_all(&recipe.input_costs())?;
     
     // Handle output based on crafting time
-    let crafting_time = item.get_crafting_time();
+    let crafting_time = recipe.crafting_time;
     
     if crafting_time == 0 {
         // Instant craft - add output immediately
-        add_crafted_item_to_inventory(player_account, item, item.get_output_quantity())?;
+        player_account.add_crafted_item(item, recipe.output_quantity)?;
+        player_account.inventory.add_all(&recipe.byproduct_list())?;
+        player_account.crafts_completed = player_account.crafts_completed.saturating_add(1);
+        award_xp(player_account, ctx.accounts.authority.key(), XP_PER_CRAFT);
+        let unlocked = player_account.achievements.record_craft(item);
+        grant_achievements(player_account, ctx.accounts.authority.key(), &unlocked);
+        track_quest(
+            player_account,
+            ctx.accounts.authority.key(),
+            ctx.accounts.season_state.days_passed,
+            QuestKind::CraftItem,
+            item_id,
+            1,
+        );
         
         emit!(crate::events::ItemCrafted {
             player: ctx.accounts.authority.key(),
@@ -58,6 +90,7 @@ pub fn handler(ctx: Context<CraftItem>, item_id: u8) -> Result<()> {
             item_id,
             started_at: current_time,
             duration: crafting_time,
+            output_quantity: recipe.output_quantity,
         });
         
         emit!(crate::events::ItemCrafted {
@@ -69,35 +102,3 @@ pub fn handler(ctx: Context<CraftItem>, item_id: u8) -> Result<()> {
     
     Ok(())
 }
-
-fn add_crafted_item_to_inventory(
-    player_account: &mut PlayerAccount,
-    item: CraftableItem,
-    quantity: u16,
-) -> Result<()> {
-    match item {
-        CraftableItem::WateringCanRefill => {
-            player_account.watering_can_uses = 10;
-        }
-        CraftableItem::Fertilizer => {
-            player_account.fertilizer_count = player_account.fertilizer_count.saturating_add(quantity);
-        }
-        CraftableItem::CompostBin => {
-            player_account.compost_bin_count = player_account.compost_bin_count.saturating_add(quantity as u8);
-        }
-        CraftableItem::Scarecrow => {
-            player_account.scarecrow_count = player_account.scarecrow_count.saturating_add(quantity as u8);
-        }
-        CraftableItem::Fence => {
-            player_account.fence_count = player_account.fence_count.saturating_add(quantity as u8);
-        }
-        CraftableItem::Sprinkler => {
-            player_account.sprinkler_count = player_account.sprinkler_count.saturating_add(quantity as u8);
-        }
-        CraftableItem::AdvancedTool => {
-            player_account.advanced_tools = player_account.advanced_tools.saturating_add(quantity as u8);
-        }
-    }
-    
-    Ok(())
-}
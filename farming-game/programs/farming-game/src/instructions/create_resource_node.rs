@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{GAME_CONFIG_SEED, PLAYER_SEED, RESOURCE_NODE_COST, RESOURCE_NODE_SEED};
+use crate::errors::FarmingError;
+use crate::events::ResourceNodeCreated;
+use crate::state::{GameConfig, NodeKind, PlayerAccount, ResourceNode};
+
+#[derive(Accounts)]
+#[instruction(region: Pubkey, node_id: u32)]
+pub struct CreateResourceNode<'info> {
+    /// Pays for nodes on the player's own land
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, authority.key().as_ref()],
+        bump = player_account.bump
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump = game_config.bump
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ResourceNode::SPACE,
+        seeds = [RESOURCE_NODE_SEED, region.as_ref(), &node_id.to_le_bytes()],
+        bump
+    )]
+    pub resource_node: Account<'info, ResourceNode>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create a resource node. `region` is the signer's own key for a private node
+/// (costs coins), or `Pubkey::default()` for a shared world node (game authority only).
+pub fn handler(ctx: Context<CreateResourceNode>, region: Pubkey, node_id: u32, kind: u8) -> Result<()> {
+    let kind = NodeKind::from_u8(kind).ok_or(FarmingError::InvalidNodeKind)?;
+    let authority = ctx.accounts.authority.key();
+    let current_time = Clock::get()?.unix_timestamp;
+
+    if region == Pubkey::default() {
+        require_keys_eq!(authority, ctx.accounts.game_config.authority, FarmingError::UnauthorizedWorldNode);
+    } else {
+        require_keys_eq!(region, authority, FarmingError::NotNodeOwner);
+        let player_account = &mut ctx.accounts.player_account;
+        require!(player_account.coins >= RESOURCE_NODE_COST, FarmingError::InsufficientPoints);
+        player_account.coins -= RESOURCE_NODE_COST;
+    }
+
+    let node = &mut ctx.accounts.resource_node;
+    node.init(region, node_id, kind, current_time, ctx.bumps.resource_node);
+    let capacity = node.capacity;
+
+    emit!(ResourceNodeCreated {
+        node: node.key(),
+        region,
+        node_id,
+        kind: kind as u8,
+        capacity,
+    });
+
+    msg!("Resource node {} created with capacity {}", node_id, capacity);
+    Ok(())
+}
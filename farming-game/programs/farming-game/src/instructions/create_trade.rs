@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_TRADE_DURATION, PLAYER_SEED, TRADE_OFFER_SEED};
+use crate::errors::FarmingError;
+use crate::events::TradeCreated;
+use crate::state::{PlayerAccount, TradeOffer, TradeTerms};
+
+#[derive(Accounts)]
+#[instruction(offer_id: u64)]
+pub struct CreateTrade<'info> {
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, authority.key().as_ref()],
+        bump = player_account.bump
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    /// Escrow PDA holding the offered side of the trade
+    #[account(
+        init,
+        payer = authority,
+        space = TradeOffer::SPACE,
+        seeds = [TRADE_OFFER_SEED, authority.key().as_ref(), &offer_id.to_le_bytes()],
+        bump
+    )]
+    pub trade_offer: Account<'info, TradeOffer>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Open a trade offer, escrowing the offered resources and coins out of the maker's account
+pub fn handler(
+    ctx: Context<CreateTrade>,
+    offer_id: u64,
+    offered: TradeTerms,
+    requested: TradeTerms,
+    duration: i64,
+    taker: Option<Pubkey>,
+) -> Result<()> {
+    require!(!offered.is_empty() && !requested.is_empty(), FarmingError::EmptyTrade);
+    require!(duration > 0 && duration <= MAX_TRADE_DURATION, FarmingError::InvalidTradeDuration);
+
+    let maker = ctx.accounts.authority.key();
+    require!(taker != Some(maker), FarmingError::CannotTradeWithSelf);
+
+    // Escrow the offered side (validates the maker holds everything first)
+    let player_account = &mut ctx.accounts.player_account;
+    require!(player_account.coins >= offered.coins, FarmingError::InsufficientPoints);
+    player_account.consume_resources(&offered.resource_list())?;
+    player_account.coins -= offered.coins;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let trade_offer = &mut ctx.accounts.trade_offer;
+    trade_offer.maker = maker;
+    trade_offer.taker = taker;
+    trade_offer.offer_id = offer_id;
+    trade_offer.offered = offered;
+    trade_offer.requested = requested;
+    trade_offer.created_at = current_time;
+    trade_offer.expires_at = current_time.saturating_add(duration);
+    trade_offer.cancelled = false;
+    trade_offer.bump = ctx.bumps.trade_offer;
+
+    emit!(TradeCreated {
+        maker,
+        trade_offer: trade_offer.key(),
+        offer_id,
+        taker,
+        offered,
+        requested,
+        expires_at: trade_offer.expires_at,
+    });
+
+    msg!("Trade offer {} created, expires at {}", offer_id, trade_offer.expires_at);
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{LISTING_SEED, PLAYER_SEED};
+use crate::errors::FarmingError;
+use crate::events::ListingDelisted;
+use crate::state::{Listing, PlayerAccount};
+
+#[derive(Accounts)]
+pub struct DelistResource<'info> {
+    /// Closed once every unsold unit is back with the seller; rent goes back to the seller
+    #[account(
+        mut,
+        seeds = [LISTING_SEED, &[listing.resource_type], &listing.listing_id.to_le_bytes()],
+        bump = listing.bump,
+        constraint = listing.seller == authority.key() @ FarmingError::NotListingSeller
+    )]
+    pub listing: Account<'info, Listing>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, authority.key().as_ref()],
+        bump = player_account.bump
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+/// Withdraw a listing and return the unsold units to the seller. Units that do not fit the
+/// seller's stacks stay listed until delist is called again.
+pub fn handler(ctx: Context<DelistResource>) -> Result<()> {
+    let listing = &mut ctx.accounts.listing;
+    let returned = listing.withdraw_capped(&mut ctx.accounts.player_account.inventory)?;
+
+    emit!(ListingDelisted {
+        listing: listing.key(),
+        listing_id: listing.listing_id,
+        seller: listing.seller,
+        resource_type: listing.resource_type,
+        quantity_returned: returned,
+        remaining: listing.quantity,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Listing {} delisted, {} units returned, {} still listed", listing.listing_id, returned, listing.quantity);
+    if listing.quantity == 0 {
+        listing.close(ctx.accounts.authority.to_account_info())?;
+    }
+    Ok(())
+}
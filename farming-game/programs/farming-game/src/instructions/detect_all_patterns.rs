@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::constants::{FIELD_SEED, HOME_FIELD_ID, PLAYER_SEED};
+use crate::state::{Field, PlayerAccount, PatternDetector};
+use crate::events::PatternMatchFound;
+
+#[derive(Accounts)]
+pub struct DetectAllPatterns<'info> {
+    #[account(
+        seeds = [PLAYER_SEED, authority.key().as_ref()],
+        bump = player_account.bump,
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    /// Extra field to scan; omit to use the home farm
+    #[account(
+        seeds = [FIELD_SEED, authority.key().as_ref(), &[field.field_id]],
+        bump = field.bump
+    )]
+    pub field: Option<Account<'info, Field>>,
+
+    #[account(signer)]
+    pub authority: Signer<'info>,
+}
+
+/// Handler: Scan the whole grid and emit one event per distinct pattern
+/// Read-only; lets clients draw pattern overlays without running the detector themselves
+pub fn handler(ctx: Context<DetectAllPatterns>) -> Result<()> {
+    let player_account = &ctx.accounts.player_account;
+    let (farm, field_id) = match ctx.accounts.field.as_deref() {
+        Some(field) => (&field.farm, field.field_id),
+        None => (&player_account.farm, HOME_FIELD_ID),
+    };
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let matches = PatternDetector::detect_all_patterns(&farm.tiles, farm.grid_size as usize, current_time);
+
+    for found in matches.iter() {
+        emit!(PatternMatchFound {
+            player: player_account.owner,
+            field_id,
+            anchor: found.anchor,
+            pattern_type: found.pattern,
+            tiles: found.tiles,
+        });
+    }
+
+    msg!("Detected {} patterns on field {}", matches.len(), field_id);
+
+    Ok(())
+}
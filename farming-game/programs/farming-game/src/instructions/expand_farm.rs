@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{DEFAULT_PLAYER_FERTILITY, HOME_FIELD_ID, MAX_GRID_SIZE, PLAYER_SEED};
+use crate::errors::FarmingError;
+use crate::events::FarmExpanded;
+use crate::state::{expansion_required_level, FarmGrid, PlayerAccount};
+
+#[derive(Accounts)]
+pub struct ExpandFarm<'info> {
+    /// Reallocated to fit one extra row and column of tiles
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, authority.key().as_ref()],
+        bump = player_account.bump,
+        constraint = player_account.farm.grid_size < MAX_GRID_SIZE @ FarmingError::MaxGridSizeReached,
+        realloc = PlayerAccount::space(player_account.farm.grid_size + 1),
+        realloc::payer = authority,
+        realloc::zero = false
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Spend coins and resources to grow the home farm from NxN to (N+1)x(N+1)
+pub fn handler(ctx: Context<ExpandFarm>) -> Result<()> {
+    let player_account = &mut ctx.accounts.player_account;
+    let new_grid_size = player_account.farm.grid_size + 1;
+
+    require!(
+        player_account.level >= expansion_required_level(new_grid_size),
+        FarmingError::LevelTooLow
+    );
+
+    let (coin_cost, resource_cost) = FarmGrid::expansion_cost(new_grid_size);
+    require!(player_account.coins >= coin_cost, FarmingError::InsufficientPoints);
+    player_account.consume_resources(&resource_cost)?;
+    player_account.coins -= coin_cost;
+
+    player_account.farm.expand(DEFAULT_PLAYER_FERTILITY);
+
+    emit!(FarmExpanded {
+        player: ctx.accounts.authority.key(),
+        field_id: HOME_FIELD_ID,
+        new_grid_size,
+        coins_spent: coin_cost,
+    });
+
+    msg!("Farm expanded to {}x{} for {} coins", new_grid_size, new_grid_size, coin_cost);
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{DEFAULT_PLAYER_FERTILITY, FIELD_SEED, MAX_GRID_SIZE, PLAYER_SEED};
+use crate::errors::FarmingError;
+use crate::events::FarmExpanded;
+use crate::state::{expansion_required_level, FarmGrid, Field, PlayerAccount};
+
+#[derive(Accounts)]
+pub struct ExpandField<'info> {
+    /// Pays for the expansion
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, authority.key().as_ref()],
+        bump = player_account.bump
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    /// Reallocated to fit one extra row and column of tiles
+    #[account(
+        mut,
+        seeds = [FIELD_SEED, authority.key().as_ref(), &[field.field_id]],
+        bump = field.bump,
+        constraint = field.farm.grid_size < MAX_GRID_SIZE @ FarmingError::MaxGridSizeReached,
+        realloc = Field::space(field.farm.grid_size + 1),
+        realloc::payer = authority,
+        realloc::zero = false
+    )]
+    pub field: Account<'info, Field>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Spend coins and resources to grow an extra field from NxN to (N+1)x(N+1)
+pub fn handler(ctx: Context<ExpandField>) -> Result<()> {
+    let field = &mut ctx.accounts.field;
+    let new_grid_size = field.farm.grid_size + 1;
+
+    require!(
+        ctx.accounts.player_account.level >= expansion_required_level(new_grid_size),
+        FarmingError::LevelTooLow
+    );
+
+    let (coin_cost, resource_cost) = FarmGrid::expansion_cost(new_grid_size);
+    let player_account = &mut ctx.accounts.player_account;
+    require!(player_account.coins >= coin_cost, FarmingError::InsufficientPoints);
+    player_account.consume_resources(&resource_cost)?;
+    player_account.coins -= coin_cost;
+
+    field.farm.expand(DEFAULT_PLAYER_FERTILITY);
+
+    emit!(FarmExpanded {
+        player: ctx.accounts.authority.key(),
+        field_id: field.field_id,
+        new_grid_size,
+        coins_spent: coin_cost,
+    });
+
+    msg!("Field {} expanded to {}x{} for {} coins", field.field_id, new_grid_size, new_grid_size, coin_cost);
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{CRAFTED_ASSET_SEED, PLAYER_SEED};
+use crate::errors::FarmingError;
+use crate::events::CraftedAssetExported;
+use crate::state::{recipes::CraftableItem, CraftedAsset, PlayerAccount};
+
+#[derive(Accounts)]
+#[instruction(asset_id: u64)]
+pub struct ExportCraftedItem<'info> {
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, authority.key().as_ref()],
+        bump = player_account.bump
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    /// New asset PDA holding the exported item
+    #[account(
+        init,
+        payer = authority,
+        space = CraftedAsset::SPACE,
+        seeds = [CRAFTED_ASSET_SEED, authority.key().as_ref(), &asset_id.to_le_bytes()],
+        bump
+    )]
+    pub crafted_asset: Account<'info, CraftedAsset>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Move one crafted structure out of the player's inventory into a standalone asset account
+pub fn handler(ctx: Context<ExportCraftedItem>, asset_id: u64, item_id: u8) -> Result<()> {
+    let item = CraftableItem::from_u8(item_id)?;
+    require!(item.is_exportable(), FarmingError::ItemNotExportable);
+
+    let player_account = &mut ctx.accounts.player_account;
+    player_account.remove_crafted_item(item)?;
+
+    let authority = ctx.accounts.authority.key();
+    let crafted_asset = &mut ctx.accounts.crafted_asset;
+    crafted_asset.owner = authority;
+    crafted_asset.minter = authority;
+    crafted_asset.asset_id = asset_id;
+    crafted_asset.item_id = item_id;
+    crafted_asset.exported_at = Clock::get()?.unix_timestamp;
+    crafted_asset.bump = ctx.bumps.crafted_asset;
+
+    emit!(CraftedAssetExported {
+        player: authority,
+        asset: crafted_asset.key(),
+        asset_id,
+        item_id,
+    });
+
+    msg!("Exported item {} as asset {}", item_id, asset_id);
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{COMPOST_FILL_PER_FIBER, COMPOST_FILL_PER_WASTE, PLAYER_SEED};
+use crate::errors::FarmingError;
+use crate::events::CompostBinFilled;
+use crate::state::{ItemId, PlayerAccount};
+
+#[derive(Accounts)]
+pub struct FillCompostBin<'info> {
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, authority.key().as_ref()],
+        bump = player_account.bump
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Feed crop waste and fiber into one compost bin
+pub fn handler(ctx: Context<FillCompostBin>, bin_index: u8, crop_waste: u16, fiber: u16) -> Result<()> {
+    let player_account = &mut ctx.accounts.player_account;
+    require!(player_account.active_compost_bins() > 0, FarmingError::NoCompostBins);
+    require!(
+        (bin_index as usize) < player_account.active_compost_bins(),
+        FarmingError::InvalidCompostBin
+    );
+    require!(crop_waste > 0 || fiber > 0, FarmingError::EmptyCompostInput);
+    require!(
+        player_account.inventory.has(ItemId::CropWaste, crop_waste),
+        ItemId::CropWaste.shortage_error()
+    );
+    require!(player_account.inventory.has(ItemId::Fiber, fiber), ItemId::Fiber.shortage_error());
+
+    let added = crop_waste
+        .checked_mul(COMPOST_FILL_PER_WASTE)
+        .and_then(|w| fiber.checked_mul(COMPOST_FILL_PER_FIBER).and_then(|f| w.checked_add(f)))
+        .ok_or(FarmingError::CompostBinFull)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let bin = &mut player_account.compost_bins[bin_index as usize];
+
+    // Settle progress under the old fill before adding more
+    bin.update(current_time);
+    require!(added <= bin.free_capacity(), FarmingError::CompostBinFull);
+    bin.fill += added;
+    let fill = bin.fill;
+
+    player_account.inventory.remove(ItemId::CropWaste, crop_waste)?;
+    player_account.inventory.remove(ItemId::Fiber, fiber)?;
+
+    emit!(CompostBinFilled {
+        player: player_account.owner,
+        bin_index,
+        crop_waste_used: crop_waste,
+        fiber_used: fiber,
+        fill,
+    });
+
+    msg!("Compost bin {} filled to {}", bin_index, fill);
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{GAME_CONFIG_SEED, MARKETPLACE_SEED, SEASON_LEADERBOARD_SEED, SEASON_STATE_SEED};
+use crate::errors::FarmingError;
+use crate::events::SeasonPrizePoolFunded;
+use crate::state::{GameConfig, Marketplace, SeasonLeaderboard, SeasonState};
+
+#[derive(Accounts)]
+pub struct FundSeasonPrizePool<'info> {
+    #[account(
+        mut,
+        seeds = [MARKETPLACE_SEED],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump = game_config.bump,
+        constraint = game_config.treasury == authority.key() @ FarmingError::UnauthorizedTreasury
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        seeds = [SEASON_STATE_SEED],
+        bump
+    )]
+    pub season_state: Account<'info, SeasonState>,
+
+    /// Leaderboard of the season in progress
+    #[account(
+        mut,
+        seeds = [SEASON_LEADERBOARD_SEED, &[season_state.current_season], &season_state.year.to_le_bytes()],
+        bump = season_leaderboard.bump
+    )]
+    pub season_leaderboard: Account<'info, SeasonLeaderboard>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Treasury: move collected marketplace fees into the current season's prize pool
+pub fn handler(ctx: Context<FundSeasonPrizePool>, amount: u64) -> Result<()> {
+    let marketplace = &mut ctx.accounts.marketplace;
+    require!(amount <= marketplace.fees_collected, FarmingError::InsufficientMarketplaceFees);
+
+    let leaderboard = &mut ctx.accounts.season_leaderboard;
+    leaderboard.fund(amount)?;
+    marketplace.fees_collected -= amount;
+
+    emit!(SeasonPrizePoolFunded {
+        season: leaderboard.season,
+        year: leaderboard.year,
+        amount,
+        prize_pool: leaderboard.prize_pool,
+    });
+
+    msg!("Added {} coins of marketplace fees to the season prize pool", amount);
+    Ok(())
+}
@@ -1,9 +1,9 @@
 use anchor_lang::prelude::*;
+use crate::constants::{RESOURCE_NODE_SEED, SEASON_STATE_SEED, XP_PER_GATHERED};
 use crate::errors::FarmingError;
-use crate::state::{PlayerAccount, resources::ResourceType};
+use crate::state::{award_xp, track_quest, ItemId, PlayerAccount, QuestKind, ResourceNode, SeasonState, ToolKind};
 
 #[derive(Accounts)]
-#[instruction(resource_type: u8, amount: u16)]
 pub struct GatherResource<'info> {
     #[account(
         mut,
@@ -12,6 +12,21 @@ pub struct GatherResource<'info> {
         has_one = owner @ FarmingError::InvalidTileIndex
     )]
     pub player_account: Account<'info, PlayerAccount>,
+
+    /// Node to gather from: the player's own, or a shared world node
+    #[account(
+        mut,
+        seeds = [RESOURCE_NODE_SEED, resource_node.region.as_ref(), &resource_node.node_id.to_le_bytes()],
+        bump = resource_node.bump,
+        constraint = resource_node.can_gather(&authority.key()) @ FarmingError::NotNodeOwner
+    )]
+    pub resource_node: Account<'info, ResourceNode>,
+
+    #[account(
+        seeds = [SEASON_STATE_SEED],
+        bump
+    )]
+    pub season_state: Account<'info, SeasonState>,
     
     #[account(signer)]
     pub authority: Signer<'info>,
@@ -21,13 +36,15 @@ pub struct GatherResource<'info> {
     pub clock: Sysvar<'info, Clock>,
 }
 
-pub fn handler(ctx: Context<GatherResource>, resource_type: u8, amount: u16) -> Result<()> {
+pub fn handler(ctx: Context<GatherResource>, amount: u16) -> Result<()> {
     let player_account = &mut ctx.accounts.player_account;
+    let node = &mut ctx.accounts.resource_node;
     let clock = &ctx.accounts.clock;
     let current_time = clock.unix_timestamp;
     
-    // Parse resource type
-    let resource = ResourceType::from_u8(resource_type)?;
+    // The node decides what is gathered
+    let resource = node.kind.resource();
+    let resource_type = resource as u8;
     
     // Validate amount
     require!(
@@ -35,55 +52,42 @@ pub fn handler(ctx: Context<GatherResource>, resource_type: u8, amount: u16) ->
         FarmingError::GatherAmountExceeded
     );
     
-    // Check cooldown
-    let cooldown_seconds = resource.gather_cooldown_seconds();
-    if cooldown_seconds > 0 {
-        let last_gather = player_account.last_gather_time[resource_type as usize];
-        if last_gather > 0 {
-            require!(
-                current_time - last_gather >= cooldown_seconds,
-                FarmingError::GatherCooldownActive
-            );
-        }
-    }
+    // The matching tool must be usable; its tier adds to the yield per action
+    let tool_kind = ToolKind::for_resource(resource).ok_or(FarmingError::InvalidNodeKind)?;
+    let tool = *player_account.tool(tool_kind);
+    require!(!tool.is_broken(), FarmingError::ToolBroken);
+    
+    // Nodes pace gathering: settle regrowth, then take from what is there
+    node.regrow(current_time);
+    require!(node.amount > 0, FarmingError::ResourceNodeDepleted);
+    
+    let wanted = amount.saturating_add(amount.saturating_mul(tool.tier.gather_bonus_percent()) / 100);
+    let gathered = node.deplete(wanted);
     
-    // Add resource to inventory (based on type)
-    let new_total = match resource {
-        ResourceType::Wood => {
-            let new = player_account.wood.saturating_add(amount);
-            require!(new <= resource.max_stack_size(), FarmingError::ResourceStackOverflow);
-            player_account.wood = new;
-            new
-        }
-        ResourceType::Stone => {
-            let new = player_account.stone.saturating_add(amount);
-            require!(new <= resource.max_stack_size(), FarmingError::ResourceStackOverflow);
-            player_account.stone = new;
-            new
-        }
-        ResourceType::Fiber => {
-            let new = player_account.fiber.saturating_add(amount);
-            require!(new <= resource.max_stack_size(), FarmingError::ResourceStackOverflow);
-            player_account.fiber = new;
-            new
-        }
-        ResourceType::Seeds => {
-            // Seeds cannot be manually gathered
-            return Err(FarmingError::GatherAmountExceeded.into());
-        }
-    };
+    // Add resource to inventory (stack limits come from the item table)
+    let item = ItemId::from_resource(resource);
+    player_account.inventory.add(item, gathered)?;
+    let new_total = player_account.inventory.amount(item);
     
-    // Update cooldown
-    if cooldown_seconds > 0 {
-        player_account.last_gather_time[resource_type as usize] = current_time;
-    }
+    player_account.tool_mut(tool_kind).wear();
+    award_xp(player_account, ctx.accounts.authority.key(), gathered as u64 * XP_PER_GATHERED);
+    track_quest(
+        player_account,
+        ctx.accounts.authority.key(),
+        ctx.accounts.season_state.days_passed,
+        QuestKind::GatherResource,
+        resource_type,
+        gathered,
+    );
     
     // Emit event
     emit!(crate::events::ResourceGathered {
         player: ctx.accounts.authority.key(),
+        node: node.key(),
         resource_type,
-        amount,
+        amount: gathered,
         new_total,
+        node_remaining: node.amount,
     });
     
     Ok(())
@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::{MIN_FERTILITY, PLAYER_SEED, SEASON_STATE_SEED, TILE_COUNT};
+use crate::constants::{CROP_WASTE_PER_HARVEST, FERTILIZER_BURN_PENALTY_PERCENT, FIELD_SEED, GAME_CONFIG_SEED, MAX_FERTILITY, MIN_FERTILITY, PLAYER_SEED, SEASON_LEADERBOARD_SEED, SEASON_STATE_SEED, XP_PER_HARVEST, XP_PER_PATTERN, XP_PER_YIELD_DIVISOR};
 use crate::errors::FarmingError;
-use crate::state::{calculate_harvest_yield_with_fertility, get_crop_config, PlayerAccount, SeasonState, PatternDetector, crop::CropType};
+use crate::state::{award_xp, calculate_harvest_yield_with_soil, grant_achievements, get_crop_config, select_farm, Field, GameConfig, ItemId, PlayerAccount, SeasonLeaderboard, SeasonState, PatternDetector, PatternType, CropRecord, SoilProfile, track_quest, QuestKind};
+use crate::events::{HarvestBreakdown, LeaderboardUpdated};
 use crate::state::tools::get_water_modifier;
 
 #[derive(Accounts)]
@@ -14,21 +15,43 @@ pub struct HarvestCrop<'info> {
     )]
     pub player_account: Account<'info, PlayerAccount>,
 
+    /// Extra field to act on; omit to use the home farm
+    #[account(
+        mut,
+        seeds = [FIELD_SEED, authority.key().as_ref(), &[field.field_id]],
+        bump = field.bump
+    )]
+    pub field: Option<Account<'info, Field>>,
+
     #[account(
         seeds = [SEASON_STATE_SEED],
         bump
     )]
     pub season_state: Account<'info, SeasonState>,
 
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump = game_config.bump
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    /// Current season's leaderboard
+    #[account(
+        mut,
+        seeds = [SEASON_LEADERBOARD_SEED, &[season_state.current_season], &season_state.year.to_le_bytes()],
+        bump = season_leaderboard.bump
+    )]
+    pub season_leaderboard: Account<'info, SeasonLeaderboard>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 }
 
 pub fn handler(ctx: Context<HarvestCrop>, tile_index: u8) -> Result<()> {
-    require!(tile_index < TILE_COUNT as u8, FarmingError::InvalidTileIndex);
-
-    let player_account = &mut ctx.accounts.player_account;
-    let tile = &player_account.farm_tiles[tile_index as usize];
+    let player_account: &mut PlayerAccount = &mut ctx.accounts.player_account;
+    let (farm, field_id) = select_farm(&mut player_account.farm, ctx.accounts.field.as_deref_mut());
+    require!((tile_index as usize) < farm.tile_count(), FarmingError::InvalidTileIndex);
+    let tile = &farm.tiles[tile_index as usize];
 
     require!(tile.crop_type != 0, FarmingError::NoActiveCrop);
 
@@ -44,145 +67,174 @@ pub fn handler(ctx: Context<HarvestCrop>, tile_index: u8) -> Result<()> {
 
     require!(time_since_mature >= 0, FarmingError::CropNotMature);
 
-    let mut yield_amount = calculate_harvest_yield_with_fertility(
+    // Record this planting in the tile's history before judging the rotation
+    let mut crop_history = tile.crop_history;
+    crop_history.push(CropRecord {
+        crop_type,
+        season: tile.planted_in_season.unwrap_or(0),
+    });
+    let rotation = crop_history.rotation_effect();
+
+    // Tiles from before soil profiles existed start from the default soil
+    let mut soil = if tile.soil.is_unset() { SoilProfile::initial() } else { tile.soil };
+
+    // Active fertilizer raises effective fertility for this harvest
+    let fertilizer_boost = tile.fertilizer.active_boost(current_time);
+    let effective_fertility = fertility.saturating_add(fertilizer_boost).min(MAX_FERTILITY);
+    let fertilizer_burned = tile.fertilizer.burned;
+
+    let mut yield_amount = calculate_harvest_yield_with_soil(
         time_since_mature,
         config.base_yield,
         config.optimal_window,
         config.max_decay_time,
         config.min_yield,
-        fertility,
+        effective_fertility,
+        &soil,
+        &config.nutrient_demand,
     )?;
+    soil.deplete(&config.nutrient_demand);
+
+    // Over-fertilized soil burns part of the crop
+    if fertilizer_burned {
+        yield_amount = yield_amount * (100 - FERTILIZER_BURN_PENALTY_PERCENT) / 100;
+    }
 
     let planted_season = tile.planted_in_season.unwrap_or(0);
     let season_idx = (planted_season.min(3)) as usize;
     let season_modifier = config.yield_modifiers[season_idx];
+    let base_yield = yield_amount;
     yield_amount = ((yield_amount as f32) * season_modifier) as u32;
 
     // Apply water modifier based on current soil moisture level
-    let water_level = player_account.water_levels[tile_index as usize];
+    let water_level = farm.water_levels[tile_index as usize];
     let water_modifier = get_water_modifier(water_level);
     yield_amount = ((yield_amount as f32) * water_modifier) as u32;
 
     // PATTERN DETECTION & BONUS APPLICATION
     // Convert tile_index to (row, col) coordinates
-    let row = (tile_index / 5) as usize;
-    let col = (tile_index % 5) as usize;
+    let (row, col) = farm.tile_coords(tile_index);
 
     // Detect all patterns at this position
     let detected_patterns = PatternDetector::detect_patterns(
-        &player_account.farm_tiles,
+        &farm.tiles,
+        farm.grid_size as usize,
         row,
         col,
         current_time,
     );
 
-    // Calculate cumulative pattern bonus (yield bonuses stack multiplicatively)
-    let mut total_yield_multiplier = 1.0f32;
-    let mut total_fertility_bonus = 0u8;
-    let mut total_water_bonus = 0u8;
-    let mut total_resource_bonus = crate::state::synergy::ResourceBonus::default();
-
-    // Apply each detected pattern's bonuses
-    for pattern in detected_patterns.iter() {
-        let bonus = pattern.get_bonus();
-        // Multiply yield bonuses
-        total_yield_multiplier *= bonus.yield_multiplier;
-        // Add other bonuses additively
-        total_fertility_bonus = total_fertility_bonus.saturating_add(bonus.fertility_bonus);
-        total_water_bonus = total_water_bonus.saturating_add(bonus.water_bonus);
-        total_resource_bonus.seeds = total_resource_bonus.seeds.saturating_add(bonus.resource_bonus.seeds);
-        total_resource_bonus.fiber = total_resource_bonus.fiber.saturating_add(bonus.resource_bonus.fiber);
-        total_resource_bonus.wood = total_resource_bonus.wood.saturating_add(bonus.resource_bonus.wood);
-        total_resource_bonus.points = total_resource_bonus.points.saturating_add(bonus.resource_bonus.points);
-    }
-
-    // Check companion planting separately
-    if let Some(companion_crop_type) = PatternDetector::check_companion_planting(
-        &player_account.farm_tiles,
+    // Companion planting: every neighbour contributes, positively or negatively
+    let companion = PatternDetector::check_companion_planting(
+        &farm.tiles,
+        farm.grid_size as usize,
         row,
         col,
         current_time,
-    ) {
-        if let (Some(center), Some(neighbor)) = (
-            crop_type_from_u8(crop_type),
-            crop_type_from_u8(companion_crop_type),
-        ) {
-            if let Some(companion_bonus) = crate::state::synergy::get_companion_bonus(center, neighbor) {
-                total_yield_multiplier *= companion_bonus.yield_multiplier;
-                total_water_bonus = total_water_bonus.saturating_add(companion_bonus.water_bonus);
-            }
-        }
-    }
+    );
+
+    // Combine patterns under the stacking rules (exclusive groups, diminishing returns, cap)
+    let patterns: Vec<PatternType> = detected_patterns.iter().map(|m| m.pattern).collect();
+    let stacked = ctx.accounts.game_config.stacking_rules().stack(&patterns, companion);
+    let total_resource_bonus = stacked.resource_bonus;
 
     // Apply pattern yield multiplier to the calculated yield
-    yield_amount = ((yield_amount as f32) * total_yield_multiplier) as u32;
+    yield_amount = ((yield_amount as f32) * stacked.yield_multiplier) as u32;
+
+    // Multi-season rotation: full legume → grain → root cycle, or repeated-crop penalty
+    yield_amount = ((yield_amount as f32) * rotation.yield_multiplier()) as u32;
 
     // Apply bonus resources to player
-    player_account.seeds = player_account.seeds.saturating_add(total_resource_bonus.seeds).min(500);
-    player_account.fiber = player_account.fiber.saturating_add(total_resource_bonus.fiber).min(500);
-    player_account.wood = player_account.wood.saturating_add(total_resource_bonus.wood).min(999);
+    let inventory = &mut player_account.inventory;
+    inventory.add_capped(ItemId::Seeds, total_resource_bonus.seeds);
+    inventory.add_capped(ItemId::Fiber, total_resource_bonus.fiber);
+    inventory.add_capped(ItemId::Wood, total_resource_bonus.wood);
     player_account.coins = player_account.coins.saturating_add(total_resource_bonus.points as u64);
 
     player_account.coins = player_account.coins.saturating_add(yield_amount as u64);
+    let harvest_coins = yield_amount as u64 + total_resource_bonus.points as u64;
+
+    // Leftover stalks and leaves can go into a compost bin
+    let inventory = &mut player_account.inventory;
+    inventory.add_capped(ItemId::CropWaste, CROP_WASTE_PER_HARVEST);
 
-    // Auto-grant resources based on crop type (before clearing tile)
+    // Auto-grant resources based on crop type (before clearing tile), capped at stack limits
     match crop_type {
         1 => {
             // Wheat: 1 seed + 2 fiber
-            player_account.seeds = player_account.seeds.saturating_add(1).min(500);
-            player_account.fiber = player_account.fiber.saturating_add(2).min(500);
+            inventory.add_capped(ItemId::Seeds, 1);
+            inventory.add_capped(ItemId::Fiber, 2);
         }
         2 => {
             // Tomato: 1 seed
-            player_account.seeds = player_account.seeds.saturating_add(1).min(500);
+            inventory.add_capped(ItemId::Seeds, 1);
         }
         3 => {
             // Corn: 2 seeds
-            player_account.seeds = player_account.seeds.saturating_add(2).min(500);
+            inventory.add_capped(ItemId::Seeds, 2);
         }
         4 => {
             // Carrot: 1 seed + 1 fiber
-            player_account.seeds = player_account.seeds.saturating_add(1).min(500);
-            player_account.fiber = player_account.fiber.saturating_add(1).min(500);
+            inventory.add_capped(ItemId::Seeds, 1);
+            inventory.add_capped(ItemId::Fiber, 1);
         }
         5 => {
             // Lettuce: 3 fiber
-            player_account.fiber = player_account.fiber.saturating_add(3).min(500);
+            inventory.add_capped(ItemId::Fiber, 3);
         }
         _ => {}
     }
 
-    let updated_tile = &mut player_account.farm_tiles[tile_index as usize];
+    let updated_tile = &mut farm.tiles[tile_index as usize];
 
-    if config.is_restorative {
-        updated_tile.fertility = updated_tile.fertility
-            .saturating_add(10)
-            .saturating_sub(config.fertility_cost)
-            .max(MIN_FERTILITY)
-            .min(100);
-        msg!("Restorative crop: fertility +10, -{}", config.fertility_cost);
-    } else {
-        updated_tile.fertility = updated_tile.fertility
-            .saturating_sub(config.fertility_cost)
-            .max(MIN_FERTILITY);
-    }
+    updated_tile.fertility = updated_tile.fertility
+        .saturating_sub(config.fertility_cost)
+        .max(MIN_FERTILITY);
 
     updated_tile.last_crop_type = updated_tile.crop_type;
     updated_tile.crop_type = 0;
     updated_tile.planted_at = 0;
-    updated_tile.restorative_bonus_used = false;
     updated_tile.planted_in_season = None;
+    updated_tile.fallow_since = current_time;
+    updated_tile.soil = soil;
+    updated_tile.fertilizer.reset_burn();
+    updated_tile.crop_history = crop_history;
+
+    // Restorative crops fix nitrogen for whatever is planted here or next door
+    if config.is_restorative {
+        farm.deposit_nitrogen(tile_index);
+        msg!("Restorative crop: nitrogen boost left on tile {} and its neighbours", tile_index);
+    }
 
-    msg!("Harvested {} coins from tile {} | Fertility now: {}",
-        yield_amount, tile_index, updated_tile.fertility);
+    msg!("Harvested {} coins from field {} tile {} | Fertility now: {}",
+        yield_amount, field_id, tile_index, farm.tiles[tile_index as usize].fertility);
+
+    emit!(HarvestBreakdown {
+        player: player_account.owner,
+        field_id,
+        plot_index: tile_index,
+        crop_type,
+        base_yield,
+        season_modifier,
+        water_modifier,
+        fertilizer_boost,
+        fertilizer_burned,
+        pattern_multiplier: stacked.yield_multiplier,
+        rotation_percent: rotation.yield_percent,
+        rotation_cycle_completed: rotation.cycle_completed,
+        repeat_streak: rotation.repeat_streak,
+        final_yield: yield_amount,
+    });
 
     // Emit pattern detection events
-    for (idx, pattern) in detected_patterns.iter().enumerate() {
-        let bonus = pattern.get_bonus();
+    for (idx, found) in detected_patterns.iter().enumerate() {
+        let bonus = found.pattern.get_bonus();
         emit!(crate::events::PatternDetected {
             player: player_account.owner,
+            field_id,
             plot_index: tile_index,
-            pattern_type: *pattern,
+            pattern_type: found.pattern,
+            tiles: found.tiles,
             yield_multiplier: bonus.yield_multiplier,
             fertility_bonus: bonus.fertility_bonus,
             water_bonus: bonus.water_bonus,
@@ -190,16 +242,37 @@ pub fn handler(ctx: Context<HarvestCrop>, tile_index: u8) -> Result<()> {
         msg!("Pattern {}: detected with {:.2}x yield multiplier", idx, bonus.yield_multiplier);
     }
 
-    Ok(())
-}
+    // XP scales with the harvest and the patterns that fed it
+    let xp = XP_PER_HARVEST
+        + yield_amount as u64 / XP_PER_YIELD_DIVISOR
+        + patterns.len() as u64 * XP_PER_PATTERN;
+    let owner = player_account.owner;
+    award_xp(player_account, owner, xp);
 
-fn crop_type_from_u8(value: u8) -> Option<CropType> {
-    match value {
-        1 => Some(CropType::Wheat),
-        2 => Some(CropType::Tomato),
-        3 => Some(CropType::Corn),
-        4 => Some(CropType::Carrot),
-        5 => Some(CropType::Lettuce),
-        _ => None,
+    let unlocked = player_account.achievements.record_harvest(crop_type, &patterns);
+    grant_achievements(player_account, owner, &unlocked);
+
+    let days_passed = ctx.accounts.season_state.days_passed;
+    track_quest(player_account, owner, days_passed, QuestKind::HarvestCrop, crop_type, 1);
+    for pattern in &patterns {
+        track_quest(player_account, owner, days_passed, QuestKind::FormPattern, *pattern as u8, 1);
     }
+
+    // Season earnings count toward the current leaderboard
+    let season = ctx.accounts.season_state.current_season;
+    let year = ctx.accounts.season_state.year;
+    let season_earnings = player_account.record_season_earnings(season, year, harvest_coins);
+    let leaderboard = &mut ctx.accounts.season_leaderboard;
+    require!(!leaderboard.frozen, FarmingError::LeaderboardFrozen);
+    if let Some(rank) = leaderboard.submit(owner, season_earnings) {
+        emit!(LeaderboardUpdated {
+            player: owner,
+            season,
+            year,
+            coins: season_earnings,
+            rank: rank as u8,
+        });
+    }
+
+    Ok(())
 }
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{CRAFTED_ASSET_SEED, PLAYER_SEED};
+use crate::errors::FarmingError;
+use crate::events::CraftedAssetImported;
+use crate::state::{recipes::CraftableItem, CraftedAsset, PlayerAccount};
+
+#[derive(Accounts)]
+pub struct ImportCraftedAsset<'info> {
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, authority.key().as_ref()],
+        bump = player_account.bump
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    /// Asset is burned on import; rent goes back to the importing owner
+    #[account(
+        mut,
+        close = authority,
+        seeds = [CRAFTED_ASSET_SEED, crafted_asset.minter.as_ref(), &crafted_asset.asset_id.to_le_bytes()],
+        bump = crafted_asset.bump,
+        constraint = crafted_asset.owner == authority.key() @ FarmingError::NotAssetOwner
+    )]
+    pub crafted_asset: Account<'info, CraftedAsset>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+/// Bring an owned asset back into the player's inventory
+pub fn handler(ctx: Context<ImportCraftedAsset>) -> Result<()> {
+    let crafted_asset = &ctx.accounts.crafted_asset;
+    let item = CraftableItem::from_u8(crafted_asset.item_id)?;
+    require!(item.is_exportable(), FarmingError::ItemNotExportable);
+
+    let player_account = &mut ctx.accounts.player_account;
+    player_account.add_crafted_item(item, 1)?;
+
+    emit!(CraftedAssetImported {
+        player: ctx.accounts.authority.key(),
+        asset: crafted_asset.key(),
+        item_id: crafted_asset.item_id,
+    });
+
+    msg!("Imported asset {} (item {})", crafted_asset.asset_id, crafted_asset.item_id);
+    Ok(())
+}
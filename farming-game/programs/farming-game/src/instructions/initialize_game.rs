@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::{DEFAULT_BASE_FERTILITY, DEFAULT_ROTATION_BONUS, DEFAULT_SEASON_LENGTH, GAME_CONFIG_SEED};
+use crate::constants::{
+    DEFAULT_BASE_FERTILITY, DEFAULT_FALLOW_REGEN_CAP, DEFAULT_MARKETPLACE_FEE_BPS, DEFAULT_PATTERN_DIMINISHING_BPS,
+    DEFAULT_PATTERN_YIELD_CAP_BPS, DEFAULT_ROTATION_BONUS,
+    DEFAULT_RUSH_COINS_PER_MINUTE, DEFAULT_SEASON_LENGTH, FALLOW_RESTORE_RATE, GAME_CONFIG_SEED, GAME_CONFIG_VERSION,
+};
 use crate::state::GameConfig;
 
 #[derive(Accounts)]
@@ -30,7 +34,14 @@ pub fn handler(ctx: Context<InitializeGame>) -> Result<()> {
     game_config.season_length = DEFAULT_SEASON_LENGTH;
     game_config.base_fertility = DEFAULT_BASE_FERTILITY;
     game_config.rotation_bonus = DEFAULT_ROTATION_BONUS;
-    game_config.config_version = 1;
+    game_config.treasury = authority;
+    game_config.marketplace_fee_bps = DEFAULT_MARKETPLACE_FEE_BPS;
+    game_config.fallow_regen_interval = FALLOW_RESTORE_RATE;
+    game_config.fallow_regen_cap = DEFAULT_FALLOW_REGEN_CAP;
+    game_config.rush_coins_per_minute = DEFAULT_RUSH_COINS_PER_MINUTE;
+    game_config.pattern_yield_cap_bps = DEFAULT_PATTERN_YIELD_CAP_BPS;
+    game_config.pattern_diminishing_bps = DEFAULT_PATTERN_DIMINISHING_BPS;
+    game_config.config_version = GAME_CONFIG_VERSION;
     game_config.bump = bump;
 
     msg!("Game config initialized by {}", authority);
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{GAME_CONFIG_SEED, MARKETPLACE_SEED};
+use crate::state::{GameConfig, Marketplace};
+
+#[derive(Accounts)]
+pub struct InitializeMarketplace<'info> {
+    /// Global marketplace PDA
+    #[account(
+        init,
+        payer = authority,
+        space = Marketplace::SPACE,
+        seeds = [MARKETPLACE_SEED],
+        bump
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump = game_config.bump,
+        has_one = authority
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeMarketplace>) -> Result<()> {
+    let marketplace = &mut ctx.accounts.marketplace;
+    let authority = ctx.accounts.authority.key();
+
+    marketplace.authority = authority;
+    marketplace.next_listing_id = 0;
+    marketplace.fees_collected = 0;
+    marketplace.volume_traded = [0u64; 4];
+    marketplace.bump = ctx.bumps.marketplace;
+
+    msg!("Marketplace initialized by {}", authority);
+    Ok(())
+}
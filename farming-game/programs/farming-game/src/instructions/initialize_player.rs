@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::{DEFAULT_PLAYER_FERTILITY, PLAYER_SEED, TILE_COUNT};
-use crate::state::{FarmTile, PlayerAccount};
+use crate::constants::{DEFAULT_PLAYER_FERTILITY, GRID_SIZE, PLAYER_SEED};
+use crate::state::{FarmGrid, PlayerAccount};
 
 #[derive(Accounts)]
 pub struct InitializePlayer<'info> {
@@ -9,7 +9,7 @@ pub struct InitializePlayer<'info> {
     #[account(
         init,
         payer = authority,
-        space = PlayerAccount::SPACE,
+        space = PlayerAccount::space(GRID_SIZE as u8),
         seeds = [PLAYER_SEED, authority.key().as_ref()],
         bump
     )]
@@ -25,18 +25,10 @@ pub fn handler(ctx: Context<InitializePlayer>) -> Result<()> {
     let authority = ctx.accounts.authority.key();
     let bump = ctx.bumps.player_account;
 
-    let mut tiles: [FarmTile; TILE_COUNT] = [FarmTile::default(); TILE_COUNT];
-    for tile in tiles.iter_mut() {
-        tile.crop_type = 0;
-        tile.planted_at = 0;
-        tile.fertility = DEFAULT_PLAYER_FERTILITY;
-        tile.last_crop_type = 0;
-        tile.restorative_bonus_used = false;
-        tile.planted_in_season = None;
-    }
+    let farm = FarmGrid::new(GRID_SIZE as u8, DEFAULT_PLAYER_FERTILITY);
 
     let player_account = &mut ctx.accounts.player_account;
-    player_account.init(authority, bump, tiles);
+    player_account.init(authority, bump, farm);
 
     msg!("Player account initialized for: {} | Starting fertility: {}", authority, DEFAULT_PLAYER_FERTILITY);
     Ok(())
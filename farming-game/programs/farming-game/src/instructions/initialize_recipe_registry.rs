@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{GAME_CONFIG_SEED, RECIPE_REGISTRY_SEED};
+use crate::state::{CraftableItem, GameConfig, Recipe, RecipeRegistry};
+
+#[derive(Accounts)]
+pub struct InitializeRecipeRegistry<'info> {
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump = game_config.bump,
+        has_one = authority
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    /// Global singleton recipe registry PDA
+    #[account(
+        init,
+        payer = authority,
+        space = RecipeRegistry::SPACE,
+        seeds = [RECIPE_REGISTRY_SEED],
+        bump
+    )]
+    pub recipe_registry: Account<'info, RecipeRegistry>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the registry seeded with the launch recipes
+pub fn handler(ctx: Context<InitializeRecipeRegistry>) -> Result<()> {
+    let registry = &mut ctx.accounts.recipe_registry;
+
+    registry.authority = ctx.accounts.authority.key();
+    for item in CraftableItem::ALL {
+        registry.recipes[item as usize] = Recipe::default_for(item);
+    }
+    registry.bump = ctx.bumps.recipe_registry;
+
+    msg!("Recipe registry initialized with {} recipes", CraftableItem::ALL.len());
+    Ok(())
+}
@@ -29,6 +29,7 @@ pub fn handler(ctx: Context<InitializeSeason>) -> Result<()> {
     season_state.current_season = 0; // Spring
     season_state.days_passed = 0;
     season_state.season_start_day = 0;
+    season_state.year = 0;
     season_state.authority = authority;
     season_state.bump = bump;
 
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{SEASON_LEADERBOARD_SEED, SEASON_STATE_SEED};
+use crate::state::{SeasonLeaderboard, SeasonState};
+
+#[derive(Accounts)]
+pub struct InitializeSeasonLeaderboard<'info> {
+    #[account(
+        seeds = [SEASON_STATE_SEED],
+        bump
+    )]
+    pub season_state: Account<'info, SeasonState>,
+
+    /// One leaderboard PDA per season and year
+    #[account(
+        init,
+        payer = payer,
+        space = SeasonLeaderboard::SPACE,
+        seeds = [SEASON_LEADERBOARD_SEED, &[season_state.current_season], &season_state.year.to_le_bytes()],
+        bump
+    )]
+    pub season_leaderboard: Account<'info, SeasonLeaderboard>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Open the leaderboard for the season in progress; anyone may pay for it. Only needed
+/// for the first season: advance_day and set_season open each following board.
+pub fn handler(ctx: Context<InitializeSeasonLeaderboard>) -> Result<()> {
+    let season_state = &ctx.accounts.season_state;
+    let leaderboard = &mut ctx.accounts.season_leaderboard;
+
+    leaderboard.open(season_state.current_season, season_state.year, ctx.bumps.season_leaderboard);
+
+    msg!("Leaderboard opened for season {} of year {}", leaderboard.season, leaderboard.year);
+    Ok(())
+}
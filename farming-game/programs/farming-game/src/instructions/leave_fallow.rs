@@ -1,8 +1,8 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::{FALLOW_RESTORE_RATE, MAX_FERTILITY, PLAYER_SEED, TILE_COUNT};
+use crate::constants::{FIELD_SEED, GAME_CONFIG_SEED, PLAYER_SEED};
 use crate::errors::FarmingError;
-use crate::state::PlayerAccount;
+use crate::state::{select_farm, Field, GameConfig, PlayerAccount, SoilProfile};
 
 #[derive(Accounts)]
 pub struct LeaveFallow<'info> {
@@ -13,29 +13,50 @@ pub struct LeaveFallow<'info> {
     )]
     pub player_account: Account<'info, PlayerAccount>,
 
+    /// Extra field to act on; omit to use the home farm
+    #[account(
+        mut,
+        seeds = [FIELD_SEED, authority.key().as_ref(), &[field.field_id]],
+        bump = field.bump
+    )]
+    pub field: Option<Account<'info, Field>>,
+
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump = game_config.bump
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 }
 
+/// Settle fallow regeneration (fertility and soil nutrients) now. Planting applies the same
+/// regeneration automatically, so this is only needed to see the update early.
 pub fn handler(ctx: Context<LeaveFallow>, tile_index: u8) -> Result<()> {
-    require!(tile_index < TILE_COUNT as u8, FarmingError::InvalidTileIndex);
-
-    let player_account = &mut ctx.accounts.player_account;
-    let tile = &mut player_account.farm_tiles[tile_index as usize];
+    let game_config = &ctx.accounts.game_config;
+    let player_account: &mut PlayerAccount = &mut ctx.accounts.player_account;
+    let (farm, field_id) = select_farm(&mut player_account.farm, ctx.accounts.field.as_deref_mut());
+    require!((tile_index as usize) < farm.tile_count(), FarmingError::InvalidTileIndex);
+    let tile = &mut farm.tiles[tile_index as usize];
 
     require!(tile.crop_type == 0, FarmingError::TileNotEmpty);
+    if tile.soil.is_unset() {
+        tile.soil = SoilProfile::initial();
+    }
 
     let current_time = Clock::get()?.unix_timestamp;
-    let time_empty = current_time.saturating_sub(tile.planted_at.max(0));
-
-    let fertility_gain = (time_empty / FALLOW_RESTORE_RATE) as u8;
+    let fertility_gain = tile.apply_fallow_regeneration(
+        current_time,
+        game_config.fallow_regen_interval,
+        game_config.fallow_regen_cap,
+    );
 
     if fertility_gain > 0 {
-        tile.fertility = tile.fertility.saturating_add(fertility_gain).min(MAX_FERTILITY);
-        tile.planted_at = current_time;
-        msg!("Fallow restored {} fertility on tile {}", fertility_gain, tile_index);
+        msg!("Fallow restored {} fertility on field {} tile {}", fertility_gain, field_id, tile_index);
     } else {
-        msg!("Not enough time passed for fallow restoration (need {} seconds)", FALLOW_RESTORE_RATE);
+        msg!("No fallow restoration yet on field {} (1 fertility per {} seconds, up to {})",
+            field_id, game_config.fallow_regen_interval, game_config.fallow_regen_cap);
     }
 
     Ok(())
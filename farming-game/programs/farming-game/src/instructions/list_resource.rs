@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{LISTING_SEED, MARKETPLACE_SEED, PLAYER_SEED};
+use crate::errors::FarmingError;
+use crate::events::ListingCreated;
+use crate::state::{resources::ResourceType, Listing, Marketplace, PlayerAccount};
+
+#[derive(Accounts)]
+#[instruction(resource_type: u8)]
+pub struct ListResource<'info> {
+    #[account(
+        mut,
+        seeds = [MARKETPLACE_SEED],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+
+    /// Per-resource listing PDA escrowing the units for sale
+    #[account(
+        init,
+        payer = authority,
+        space = Listing::SPACE,
+        seeds = [LISTING_SEED, &[resource_type], &marketplace.next_listing_id.to_le_bytes()],
+        bump
+    )]
+    pub listing: Account<'info, Listing>,
+
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, authority.key().as_ref()],
+        bump = player_account.bump
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Put resources up for sale at a fixed price per unit
+pub fn handler(ctx: Context<ListResource>, resource_type: u8, quantity: u16, price_per_unit: u64) -> Result<()> {
+    ResourceType::from_u8(resource_type)?;
+    require!(quantity > 0, FarmingError::InvalidListingQuantity);
+    require!(price_per_unit > 0, FarmingError::InvalidListingPrice);
+
+    // Escrow the units out of the seller's inventory
+    let player_account = &mut ctx.accounts.player_account;
+    player_account.consume_resources(&[(resource_type, quantity)])?;
+
+    let marketplace = &mut ctx.accounts.marketplace;
+    let listing_id = marketplace.next_listing_id;
+    marketplace.next_listing_id = listing_id.saturating_add(1);
+
+    let seller = ctx.accounts.authority.key();
+    let current_time = Clock::get()?.unix_timestamp;
+    let listing = &mut ctx.accounts.listing;
+    listing.seller = seller;
+    listing.listing_id = listing_id;
+    listing.resource_type = resource_type;
+    listing.price_per_unit = price_per_unit;
+    listing.quantity = quantity;
+    listing.created_at = current_time;
+    listing.bump = ctx.bumps.listing;
+
+    emit!(ListingCreated {
+        listing: listing.key(),
+        listing_id,
+        seller,
+        resource_type,
+        quantity,
+        price_per_unit,
+        timestamp: current_time,
+    });
+
+    msg!("Listing {}: {} x resource {} at {} coins each", listing_id, quantity, resource_type, price_per_unit);
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::constants::GAME_CONFIG_SEED;
+use crate::errors::FarmingError;
+use crate::state::GameConfig;
+
+#[derive(Accounts)]
+pub struct MigrateGameConfig<'info> {
+    /// CHECK: May still be in an older, shorter layout. The seeds pin the address and
+    /// the handler checks the discriminator, version and authority before rewriting it.
+    #[account(
+        mut,
+        seeds = [GAME_CONFIG_SEED],
+        bump
+    )]
+    pub game_config: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Upgrade the config PDA to the current layout, growing it and topping up rent as needed
+pub fn handler(ctx: Context<MigrateGameConfig>) -> Result<()> {
+    let info = ctx.accounts.game_config.to_account_info();
+    let (config, from_version) = {
+        let data = info.try_borrow_data()?;
+        let from_version = data[data.len().saturating_sub(2)];
+        (GameConfig::from_versioned_bytes(&data)?, from_version)
+    };
+    require_keys_eq!(config.authority, ctx.accounts.authority.key(), FarmingError::UnauthorizedConfigMigration);
+
+    if info.data_len() < GameConfig::SPACE {
+        let rent = Rent::get()?.minimum_balance(GameConfig::SPACE);
+        let shortfall = rent.saturating_sub(info.lamports());
+        if shortfall > 0 {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: info.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        info.resize(GameConfig::SPACE)?;
+    }
+
+    let mut data = info.try_borrow_mut_data()?;
+    config.try_serialize(&mut &mut data[..])?;
+
+    msg!("Game config migrated from version {} to {}", from_version, config.config_version);
+    Ok(())
+}
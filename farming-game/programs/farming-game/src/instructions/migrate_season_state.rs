@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::constants::SEASON_STATE_SEED;
+use crate::errors::FarmingError;
+use crate::state::SeasonState;
+
+#[derive(Accounts)]
+pub struct MigrateSeasonState<'info> {
+    /// CHECK: May still be in the layout without `year`. The seeds pin the address and
+    /// the handler checks the discriminator, layout and authority before rewriting it.
+    #[account(
+        mut,
+        seeds = [SEASON_STATE_SEED],
+        bump
+    )]
+    pub season_state: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Upgrade the season PDA to the current layout, growing it and topping up rent as needed
+pub fn handler(ctx: Context<MigrateSeasonState>) -> Result<()> {
+    let info = ctx.accounts.season_state.to_account_info();
+    let season_state = SeasonState::from_versioned_bytes(&info.try_borrow_data()?)?;
+    require_keys_eq!(
+        season_state.authority,
+        ctx.accounts.authority.key(),
+        FarmingError::UnauthorizedSeasonMigration
+    );
+
+    if info.data_len() < SeasonState::SPACE {
+        let rent = Rent::get()?.minimum_balance(SeasonState::SPACE);
+        let shortfall = rent.saturating_sub(info.lamports());
+        if shortfall > 0 {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: info.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        info.resize(SeasonState::SPACE)?;
+    }
+
+    let mut data = info.try_borrow_mut_data()?;
+    season_state.try_serialize(&mut &mut data[..])?;
+
+    msg!("Season state migrated (year {})", season_state.year);
+    Ok(())
+}
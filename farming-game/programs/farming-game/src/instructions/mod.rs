@@ -17,6 +17,36 @@ pub mod craft_item;
 pub mod claim_crafted_item;
 pub mod collect_compost;
 pub mod check_patterns;
+pub mod export_crafted_item;
+pub mod transfer_crafted_asset;
+pub mod import_crafted_asset;
+pub mod create_trade;
+pub mod accept_trade;
+pub mod cancel_trade;
+pub mod update_game_config;
+pub mod initialize_marketplace;
+pub mod list_resource;
+pub mod buy_partial;
+pub mod delist_resource;
+pub mod withdraw_marketplace_fees;
+pub mod expand_farm;
+pub mod buy_field;
+pub mod expand_field;
+pub mod detect_all_patterns;
+pub mod fill_compost_bin;
+pub mod create_resource_node;
+pub mod upgrade_tool;
+pub mod repair_tool;
+pub mod initialize_recipe_registry;
+pub mod update_recipe;
+pub mod unlock_recipe;
+pub mod rush_crafting;
+pub mod claim_quest;
+pub mod initialize_season_leaderboard;
+pub mod claim_season_prize;
+pub mod migrate_game_config;
+pub mod fund_season_prize_pool;
+pub mod migrate_season_state;
 
 // Use glob imports but acknowledge the ambiguity is intentional
 #[allow(ambiguous_glob_reexports)]
@@ -57,3 +87,63 @@ pub use claim_crafted_item::*;
 pub use collect_compost::*;
 #[allow(ambiguous_glob_reexports)]
 pub use check_patterns::*;
+#[allow(ambiguous_glob_reexports)]
+pub use export_crafted_item::*;
+#[allow(ambiguous_glob_reexports)]
+pub use transfer_crafted_asset::*;
+#[allow(ambiguous_glob_reexports)]
+pub use import_crafted_asset::*;
+#[allow(ambiguous_glob_reexports)]
+pub use create_trade::*;
+#[allow(ambiguous_glob_reexports)]
+pub use accept_trade::*;
+#[allow(ambiguous_glob_reexports)]
+pub use cancel_trade::*;
+#[allow(ambiguous_glob_reexports)]
+pub use update_game_config::*;
+#[allow(ambiguous_glob_reexports)]
+pub use initialize_marketplace::*;
+#[allow(ambiguous_glob_reexports)]
+pub use list_resource::*;
+#[allow(ambiguous_glob_reexports)]
+pub use buy_partial::*;
+#[allow(ambiguous_glob_reexports)]
+pub use delist_resource::*;
+#[allow(ambiguous_glob_reexports)]
+pub use withdraw_marketplace_fees::*;
+#[allow(ambiguous_glob_reexports)]
+pub use expand_farm::*;
+#[allow(ambiguous_glob_reexports)]
+pub use buy_field::*;
+#[allow(ambiguous_glob_reexports)]
+pub use expand_field::*;
+#[allow(ambiguous_glob_reexports)]
+pub use detect_all_patterns::*;
+#[allow(ambiguous_glob_reexports)]
+pub use fill_compost_bin::*;
+#[allow(ambiguous_glob_reexports)]
+pub use create_resource_node::*;
+#[allow(ambiguous_glob_reexports)]
+pub use upgrade_tool::*;
+#[allow(ambiguous_glob_reexports)]
+pub use repair_tool::*;
+#[allow(ambiguous_glob_reexports)]
+pub use initialize_recipe_registry::*;
+#[allow(ambiguous_glob_reexports)]
+pub use update_recipe::*;
+#[allow(ambiguous_glob_reexports)]
+pub use unlock_recipe::*;
+#[allow(ambiguous_glob_reexports)]
+pub use rush_crafting::*;
+#[allow(ambiguous_glob_reexports)]
+pub use claim_quest::*;
+#[allow(ambiguous_glob_reexports)]
+pub use initialize_season_leaderboard::*;
+#[allow(ambiguous_glob_reexports)]
+pub use claim_season_prize::*;
+#[allow(ambiguous_glob_reexports)]
+pub use migrate_game_config::*;
+#[allow(ambiguous_glob_reexports)]
+pub use fund_season_prize_pool::*;
+#[allow(ambiguous_glob_reexports)]
+pub use migrate_season_state::*;
@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::{DEFAULT_MIGRATED_FERTILITY, DEFAULT_ROTATION_BONUS, PLAYER_SEED, SEASON_STATE_SEED, TILE_COUNT};
+use crate::constants::{DEFAULT_MIGRATED_FERTILITY, DEFAULT_ROTATION_BONUS, FIELD_SEED, GAME_CONFIG_SEED, PLAYER_SEED, SEASON_STATE_SEED};
 use crate::errors::FarmingError;
-use crate::state::{get_crop_config, PlayerAccount, SeasonState};
+use crate::events::NitrogenBoostConsumed;
+use crate::state::{get_crop_config, select_farm, CropType, Field, GameConfig, PlayerAccount, SeasonState, SoilProfile};
 
 #[derive(Accounts)]
 pub struct PlantCrop<'info> {
@@ -13,21 +14,39 @@ pub struct PlantCrop<'info> {
     )]
     pub player_account: Account<'info, PlayerAccount>,
 
+    /// Extra field to act on; omit to use the home farm
+    #[account(
+        mut,
+        seeds = [FIELD_SEED, authority.key().as_ref(), &[field.field_id]],
+        bump = field.bump
+    )]
+    pub field: Option<Account<'info, Field>>,
+
     #[account(
         seeds = [SEASON_STATE_SEED],
         bump
     )]
     pub season_state: Account<'info, SeasonState>,
 
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump = game_config.bump
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 }
 
 pub fn handler(ctx: Context<PlantCrop>, tile_index: u8, crop_type: u8) -> Result<()> {
-    require!(tile_index < TILE_COUNT as u8, FarmingError::InvalidTileIndex);
-    require!(crop_type >= 1 && crop_type <= 5, FarmingError::InvalidCropType);
+    let crop = CropType::from_u8(crop_type).ok_or(FarmingError::InvalidCropType)?;
+    require!(ctx.accounts.player_account.level >= crop.required_level(), FarmingError::LevelTooLow);
 
-    let player_account = &mut ctx.accounts.player_account;
+    let game_config = &ctx.accounts.game_config;
+    let player_account: &mut PlayerAccount = &mut ctx.accounts.player_account;
+    let owner = player_account.owner;
+    let (farm, field_id) = select_farm(&mut player_account.farm, ctx.accounts.field.as_deref_mut());
+    require!((tile_index as usize) < farm.tile_count(), FarmingError::InvalidTileIndex);
     let config = get_crop_config(crop_type)?;
     let current_season = ctx.accounts.season_state.current_season;
 
@@ -35,17 +54,30 @@ pub fn handler(ctx: Context<PlantCrop>, tile_index: u8, crop_type: u8) -> Result
         return Err(FarmingError::InvalidSeasonForCrop.into());
     }
 
-    require!(player_account.farm_tiles[tile_index as usize].crop_type == 0, FarmingError::TileNotEmpty);
+    require!(farm.tiles[tile_index as usize].crop_type == 0, FarmingError::TileNotEmpty);
 
     let current_time = Clock::get()?.unix_timestamp;
     
     // Check and update tile first, then update water separately to avoid borrow conflicts
     {
-        let tile = &mut player_account.farm_tiles[tile_index as usize];
+        let tile = &mut farm.tiles[tile_index as usize];
         
         if tile.fertility == 0 {
             tile.fertility = DEFAULT_MIGRATED_FERTILITY;
         }
+        if tile.soil.is_unset() {
+            tile.soil = SoilProfile::initial();
+        }
+
+        // Settle any fertility regained while the tile lay fallow
+        let fallow_gain = tile.apply_fallow_regeneration(
+            current_time,
+            game_config.fallow_regen_interval,
+            game_config.fallow_regen_cap,
+        );
+        if fallow_gain > 0 {
+            msg!("Fallow regeneration: fertility +{}", fallow_gain);
+        }
 
         let is_rotation = tile.last_crop_type != 0 && tile.last_crop_type != crop_type;
         if is_rotation {
@@ -53,17 +85,32 @@ pub fn handler(ctx: Context<PlantCrop>, tile_index: u8, crop_type: u8) -> Result
             msg!("Crop rotation bonus! Fertility +{}", DEFAULT_ROTATION_BONUS);
         }
 
+        // Nitrogen left by a restorative harvest feeds the next non-restorative crop
+        if !config.is_restorative {
+            if let Some(boost) = tile.consume_nitrogen() {
+                emit!(NitrogenBoostConsumed {
+                    player: owner,
+                    field_id,
+                    plot_index: tile_index,
+                    crop_type,
+                    nitrogen_gained: boost,
+                    soil_nitrogen: tile.soil.nitrogen,
+                });
+                msg!("Nitrogen boost consumed! Soil nitrogen +{}", boost);
+            }
+        }
+
         tile.crop_type = crop_type;
         tile.planted_at = current_time;
-        tile.restorative_bonus_used = false;
         tile.planted_in_season = Some(current_season);
+        tile.fallow_since = 0;
     }
 
     // Initialize water level for newly planted crop (70% = recently tilled soil)
-    player_account.water_levels[tile_index as usize] = 70;
-    player_account.last_watered[tile_index as usize] = current_time;
+    farm.water_levels[tile_index as usize] = 70;
+    farm.last_watered[tile_index as usize] = current_time;
 
-    msg!("Crop type {} planted on tile {} at {} | Fertility: {}",
-        crop_type, tile_index, current_time, player_account.farm_tiles[tile_index as usize].fertility);
+    msg!("Crop type {} planted on field {} tile {} at {} | Fertility: {}",
+        crop_type, field_id, tile_index, current_time, farm.tiles[tile_index as usize].fertility);
     Ok(())
 }
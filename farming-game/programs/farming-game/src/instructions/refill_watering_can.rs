@@ -37,8 +37,8 @@ pub fn handler(ctx: Context<RefillWateringCan>) -> Result<()> {
     // Deduct points
     player_account.coins = player_account.coins.saturating_sub(refill_cost as u64);
     
-    // Refill watering can to 10 uses
-    player_account.watering_can_uses = 10;
+    // Refill watering can to its tier's capacity
+    player_account.refill_watering_can();
     
     // Emit event
     emit!(CanRefilled {
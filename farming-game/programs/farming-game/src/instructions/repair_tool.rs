@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::constants::PLAYER_SEED;
+use crate::errors::FarmingError;
+use crate::events::ToolRepaired;
+use crate::state::{PlayerAccount, ToolKind};
+
+#[derive(Accounts)]
+pub struct RepairTool<'info> {
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, authority.key().as_ref()],
+        bump = player_account.bump,
+        has_one = owner @ FarmingError::NotPlayerOwner
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+    
+    #[account(signer)]
+    pub authority: Signer<'info>,
+    
+    pub owner: Signer<'info>,
+}
+
+/// Craft a repair from resources, restoring the tool to full durability for its tier
+pub fn handler(ctx: Context<RepairTool>, tool_kind: u8) -> Result<()> {
+    let player_account = &mut ctx.accounts.player_account;
+    
+    let kind = ToolKind::from_u8(tool_kind).ok_or(FarmingError::InvalidToolKind)?;
+    let tool = player_account.repair_tool(kind)?;
+    
+    emit!(ToolRepaired {
+        player: ctx.accounts.authority.key(),
+        tool_kind,
+        tier: tool.tier as u8,
+        durability: tool.durability,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::constants::{GAME_CONFIG_SEED, RECIPE_REGISTRY_SEED, SEASON_STATE_SEED};
+use crate::errors::FarmingError;
+use crate::instructions::claim_crafted_item::finish_crafting_job;
+use crate::state::{GameConfig, PlayerAccount, RecipeRegistry, SeasonState};
+
+#[derive(Accounts)]
+pub struct RushCrafting<'info> {
+    #[account(
+        mut,
+        seeds = [b"player", authority.key().as_ref()],
+        bump = player_account.bump,
+        has_one = owner @ FarmingError::InvalidTileIndex
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    #[account(
+        seeds = [RECIPE_REGISTRY_SEED],
+        bump = recipe_registry.bump
+    )]
+    pub recipe_registry: Account<'info, RecipeRegistry>,
+
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump = game_config.bump
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        seeds = [SEASON_STATE_SEED],
+        bump
+    )]
+    pub season_state: Account<'info, SeasonState>,
+    
+    #[account(signer)]
+    pub authority: Signer<'info>,
+    
+    pub owner: Signer<'info>,
+    
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Pay coins for the time left on the current job and claim it immediately
+pub fn handler(ctx: Context<RushCrafting>) -> Result<()> {
+    let player_account = &mut ctx.accounts.player_account;
+    let current_time = ctx.accounts.clock.unix_timestamp;
+    
+    let job = player_account.crafting_queue.ok_or(FarmingError::NoCraftingInProgress)?;
+    let remaining = job.started_at + job.duration - current_time;
+    require!(remaining > 0, FarmingError::CraftingAlreadyComplete);
+    
+    let cost = ctx.accounts.game_config.rush_cost(remaining);
+    require!(player_account.coins >= cost, FarmingError::InsufficientPoints);
+    player_account.coins -= cost;
+    
+    finish_crafting_job(
+        player_account,
+        &ctx.accounts.recipe_registry,
+        ctx.accounts.authority.key(),
+        ctx.accounts.season_state.days_passed,
+        cost,
+    )
+}
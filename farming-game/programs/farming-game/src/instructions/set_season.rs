@@ -1,10 +1,11 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::SEASON_STATE_SEED;
+use crate::constants::{SEASON_LEADERBOARD_SEED, SEASON_STATE_SEED};
 use crate::errors::FarmingError;
-use crate::state::SeasonState;
+use crate::state::{roll_over_season, SeasonLeaderboard, SeasonState};
 
 #[derive(Accounts)]
+#[instruction(new_season: u8)]
 pub struct SetSeason<'info> {
     #[account(
         mut,
@@ -14,17 +15,46 @@ pub struct SetSeason<'info> {
     )]
     pub season_state: Account<'info, SeasonState>,
 
+    /// Leaderboard of the season being left; frozen like at a normal rollover
+    #[account(
+        mut,
+        seeds = [SEASON_LEADERBOARD_SEED, &[season_state.current_season], &season_state.year.to_le_bytes()],
+        bump = season_leaderboard.bump
+    )]
+    pub season_leaderboard: Account<'info, SeasonLeaderboard>,
+
+    /// Leaderboard of the season being set, opened so harvests can rank right away
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = SeasonLeaderboard::SPACE,
+        seeds = [SEASON_LEADERBOARD_SEED, &[new_season], &season_state.set_season_target(new_season).1.to_le_bytes()],
+        bump
+    )]
+    pub target_season_leaderboard: Account<'info, SeasonLeaderboard>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
+/// Jump straight to another season. Rolls over like advance_day does: the current board
+/// freezes, the target board opens, and a season at or before the current one starts a new year.
 pub fn handler(ctx: Context<SetSeason>, new_season: u8) -> Result<()> {
     require!(new_season < 4, FarmingError::InvalidSeasonIndex);
 
     let season_state = &mut ctx.accounts.season_state;
-    season_state.current_season = new_season;
-    season_state.season_start_day = season_state.days_passed;
+    let (season, year) = season_state.set_season_target(new_season);
+    roll_over_season(
+        season_state,
+        &mut ctx.accounts.season_leaderboard,
+        &mut ctx.accounts.target_season_leaderboard,
+        season,
+        year,
+        ctx.bumps.target_season_leaderboard,
+    );
 
-    msg!("Season manually set to {}", new_season);
+    msg!("Season manually set to {} (year {})", season, year);
     Ok(())
 }
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::CRAFTED_ASSET_SEED;
+use crate::errors::FarmingError;
+use crate::events::CraftedAssetTransferred;
+use crate::state::CraftedAsset;
+
+#[derive(Accounts)]
+pub struct TransferCraftedAsset<'info> {
+    #[account(
+        mut,
+        seeds = [CRAFTED_ASSET_SEED, crafted_asset.minter.as_ref(), &crafted_asset.asset_id.to_le_bytes()],
+        bump = crafted_asset.bump,
+        constraint = crafted_asset.owner == authority.key() @ FarmingError::NotAssetOwner
+    )]
+    pub crafted_asset: Account<'info, CraftedAsset>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Hand an exported asset over to another player
+pub fn handler(ctx: Context<TransferCraftedAsset>, new_owner: Pubkey) -> Result<()> {
+    let crafted_asset = &mut ctx.accounts.crafted_asset;
+    let previous_owner = crafted_asset.owner;
+
+    crafted_asset.owner = new_owner;
+
+    emit!(CraftedAssetTransferred {
+        asset: crafted_asset.key(),
+        from: previous_owner,
+        to: new_owner,
+        item_id: crafted_asset.item_id,
+    });
+
+    msg!("Asset {} transferred from {} to {}", crafted_asset.asset_id, previous_owner, new_owner);
+    Ok(())
+}
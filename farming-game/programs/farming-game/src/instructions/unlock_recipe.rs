@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::constants::RECIPE_REGISTRY_SEED;
+use crate::errors::FarmingError;
+use crate::events::RecipeUnlocked;
+use crate::state::{CraftableItem, PlayerAccount, RecipeRegistry};
+
+#[derive(Accounts)]
+pub struct UnlockRecipe<'info> {
+    #[account(
+        mut,
+        seeds = [b"player", authority.key().as_ref()],
+        bump = player_account.bump,
+        has_one = owner @ FarmingError::InvalidTileIndex
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    #[account(
+        seeds = [RECIPE_REGISTRY_SEED],
+        bump = recipe_registry.bump
+    )]
+    pub recipe_registry: Account<'info, RecipeRegistry>,
+    
+    #[account(signer)]
+    pub authority: Signer<'info>,
+    
+    pub owner: Signer<'info>,
+}
+
+/// Unlock a recipe once the player meets its prerequisites
+pub fn handler(ctx: Context<UnlockRecipe>, item_id: u8) -> Result<()> {
+    let player_account = &mut ctx.accounts.player_account;
+    let recipe = ctx.accounts.recipe_registry.recipe(item_id)?;
+    CraftableItem::from_u8(item_id)?;
+    
+    require!(
+        !recipe.is_starter() && !player_account.has_unlocked_recipe(item_id),
+        FarmingError::RecipeAlreadyUnlocked
+    );
+    require!(player_account.level >= recipe.required_level, FarmingError::LevelTooLow);
+    require!(recipe.prerequisites_met(player_account)?, FarmingError::RecipePrerequisitesNotMet);
+    
+    player_account.unlock_recipe(item_id);
+    
+    emit!(RecipeUnlocked {
+        player: ctx.accounts.authority.key(),
+        item_id,
+    });
+    
+    Ok(())
+}
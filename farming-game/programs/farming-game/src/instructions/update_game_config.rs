@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{BPS_DENOMINATOR, GAME_CONFIG_SEED, MAX_FERTILITY, MAX_MARKETPLACE_FEE_BPS};
+use crate::errors::FarmingError;
+use crate::state::{GameConfig, GameConfigUpdate};
+
+#[derive(Accounts)]
+pub struct UpdateGameConfig<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_CONFIG_SEED],
+        bump = game_config.bump,
+        has_one = authority
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateGameConfig>, update: GameConfigUpdate) -> Result<()> {
+    let game_config = &mut ctx.accounts.game_config;
+
+    if let Some(treasury) = update.treasury {
+        game_config.treasury = treasury;
+    }
+
+    if let Some(fee_bps) = update.marketplace_fee_bps {
+        require!(fee_bps <= MAX_MARKETPLACE_FEE_BPS, FarmingError::InvalidMarketplaceFee);
+        game_config.marketplace_fee_bps = fee_bps;
+    }
+
+    if let Some(interval) = update.fallow_regen_interval {
+        require!(interval > 0, FarmingError::InvalidFallowConfig);
+        game_config.fallow_regen_interval = interval;
+    }
+
+    if let Some(cap) = update.fallow_regen_cap {
+        require!(cap <= MAX_FERTILITY, FarmingError::InvalidFallowConfig);
+        game_config.fallow_regen_cap = cap;
+    }
+
+    if let Some(rate) = update.rush_coins_per_minute {
+        require!(rate > 0, FarmingError::InvalidRushRate);
+        game_config.rush_coins_per_minute = rate;
+    }
+
+    // A cap below 1.0x would turn patterns into penalties; a factor above 1.0 would grow each bonus
+    if let Some(cap_bps) = update.pattern_yield_cap_bps {
+        require!(cap_bps as u64 >= BPS_DENOMINATOR, FarmingError::InvalidStackingRules);
+        game_config.pattern_yield_cap_bps = cap_bps;
+    }
+
+    if let Some(diminishing_bps) = update.pattern_diminishing_bps {
+        require!(diminishing_bps as u64 <= BPS_DENOMINATOR, FarmingError::InvalidStackingRules);
+        game_config.pattern_diminishing_bps = diminishing_bps;
+    }
+
+    msg!("Game config updated by {}", game_config.authority);
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::RECIPE_REGISTRY_SEED;
+use crate::errors::FarmingError;
+use crate::events::RecipeUpdated;
+use crate::state::{Recipe, RecipeRegistry};
+
+#[derive(Accounts)]
+pub struct UpdateRecipe<'info> {
+    #[account(
+        mut,
+        seeds = [RECIPE_REGISTRY_SEED],
+        bump = recipe_registry.bump,
+        has_one = authority
+    )]
+    pub recipe_registry: Account<'info, RecipeRegistry>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Admin: replace the recipe for an item id. Disabled recipes cannot be crafted.
+pub fn handler(ctx: Context<UpdateRecipe>, item_id: u8, recipe: Recipe) -> Result<()> {
+    let registry = &mut ctx.accounts.recipe_registry;
+    let slot = registry
+        .recipes
+        .get_mut(item_id as usize)
+        .ok_or(FarmingError::InvalidCraftableItem)?;
+
+    if recipe.enabled {
+        recipe.validate()?;
+    }
+    *slot = recipe;
+
+    emit!(RecipeUpdated {
+        item_id,
+        enabled: recipe.enabled,
+    });
+
+    Ok(())
+}
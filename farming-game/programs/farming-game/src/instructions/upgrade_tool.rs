@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::constants::PLAYER_SEED;
+use crate::errors::FarmingError;
+use crate::events::ToolUpgraded;
+use crate::state::{PlayerAccount, ToolKind};
+
+#[derive(Accounts)]
+pub struct UpgradeTool<'info> {
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, authority.key().as_ref()],
+        bump = player_account.bump,
+        has_one = owner @ FarmingError::NotPlayerOwner
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+    
+    #[account(signer)]
+    pub authority: Signer<'info>,
+    
+    pub owner: Signer<'info>,
+}
+
+/// Craft a tool up to its next tier from resources. The upgraded tool comes back at full durability.
+pub fn handler(ctx: Context<UpgradeTool>, tool_kind: u8) -> Result<()> {
+    let player_account = &mut ctx.accounts.player_account;
+    
+    let kind = ToolKind::from_u8(tool_kind).ok_or(FarmingError::InvalidToolKind)?;
+    let tool = player_account.upgrade_tool(kind)?;
+    
+    emit!(ToolUpgraded {
+        player: ctx.accounts.authority.key(),
+        tool_kind,
+        tier: tool.tier as u8,
+        durability: tool.durability,
+    });
+    
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
+use crate::constants::{FERTILIZER_BURN_PENALTY_PERCENT, FIELD_SEED, GAME_CONFIG_SEED, MAX_FERTILITY};
 use crate::errors::FarmingError;
-use crate::state::PlayerAccount;
+use crate::state::{select_farm, FertilizerType, Field, GameConfig, ItemId, PlayerAccount, SoilProfile};
 use crate::events::FertilizerApplied;
 
 #[derive(Accounts)]
@@ -13,6 +14,20 @@ pub struct UseFertilizer<'info> {
         has_one = owner @ FarmingError::InvalidPlotIndex
     )]
     pub player_account: Account<'info, PlayerAccount>,
+
+    /// Extra field to act on; omit to use the home farm
+    #[account(
+        mut,
+        seeds = [FIELD_SEED, authority.key().as_ref(), &[field.field_id]],
+        bump = field.bump
+    )]
+    pub field: Option<Account<'info, Field>>,
+
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump = game_config.bump
+    )]
+    pub game_config: Account<'info, GameConfig>,
     
     #[account(signer)]
     pub authority: Signer<'info>,
@@ -20,36 +35,58 @@ pub struct UseFertilizer<'info> {
     pub owner: Signer<'info>,
 }
 
-pub fn handler(ctx: Context<UseFertilizer>, plot_index: u8) -> Result<()> {
-    let player_account = &mut ctx.accounts.player_account;
+pub fn handler(ctx: Context<UseFertilizer>, plot_index: u8, fertilizer_type: u8) -> Result<()> {
+    let fertilizer = FertilizerType::from_u8(fertilizer_type).ok_or(FarmingError::InvalidFertilizerType)?;
+    let player_account: &mut PlayerAccount = &mut ctx.accounts.player_account;
+    let (farm, field_id) = select_farm(&mut player_account.farm, ctx.accounts.field.as_deref_mut());
     
     // Validate plot index
-    if plot_index >= 25 {
+    if plot_index as usize >= farm.tile_count() {
         return Err(FarmingError::InvalidPlotIndex.into());
     }
     
-    // Check fertilizer available
-    if player_account.fertilizer_count == 0 {
-        return Err(FarmingError::InsufficientFertilizer.into());
-    }
-    
-    // Get current fertility
-    let current_fertility = player_account.farm_tiles[plot_index as usize].fertility as u16;
-    
-    // Add 20 fertility, capped at 100
-    let new_fertility = std::cmp::min(current_fertility + 20, 100) as u8;
+    // Each kind comes from its own stock
+    let stock = match fertilizer {
+        FertilizerType::Compost => ItemId::Compost,
+        FertilizerType::Crafted => ItemId::Fertilizer,
+        FertilizerType::Premium => ItemId::PremiumFertilizer,
+    };
+    player_account.inventory.remove(stock, 1)?;
     
-    // Update plot fertility
-    player_account.farm_tiles[plot_index as usize].fertility = new_fertility;
-    
-    // Decrement fertilizer count
-    player_account.fertilizer_count = player_account.fertilizer_count.saturating_sub(1);
+    let current_time = Clock::get()?.unix_timestamp;
+    let config = fertilizer.get_config();
+    let tile = &mut farm.tiles[plot_index as usize];
+    if tile.soil.is_unset() {
+        tile.soil = SoilProfile::initial();
+    }
+
+    // Settle fallow regeneration first so the fertilizer does not eat into its headroom
+    let game_config = &ctx.accounts.game_config;
+    tile.apply_fallow_regeneration(current_time, game_config.fallow_regen_interval, game_config.fallow_regen_cap);
+
+    // Permanent side effects on the soil; fertility is capped at 100
+    tile.soil.apply(&config.soil);
+    tile.fertility = tile.fertility.saturating_add(config.soil.fertility).min(MAX_FERTILITY);
+
+    // Temporary boost, and burn if the tile was fed too much within the window
+    let burned_now = tile.fertilizer.apply(&config, current_time);
+    if burned_now {
+        msg!("Field {} tile {} over-fertilized: next harvest loses {}% yield",
+            field_id, plot_index, FERTILIZER_BURN_PENALTY_PERCENT);
+    }
     
     // Emit event
     emit!(FertilizerApplied {
         player: ctx.accounts.authority.key(),
+        field_id,
         plot_index,
-        new_fertility,
+        new_fertility: tile.fertility,
+        fertilizer_type,
+        boost: tile.fertilizer.boost,
+        boost_until: tile.fertilizer.boost_until,
+        burn_load: tile.fertilizer.load,
+        burned: tile.fertilizer.burned,
+        burned_now,
     });
     
     Ok(())
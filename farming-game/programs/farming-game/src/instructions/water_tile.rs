@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
+use crate::constants::FIELD_SEED;
 use crate::errors::FarmingError;
-use crate::state::PlayerAccount;
+use crate::state::{select_farm, Field, PlayerAccount, ToolKind};
 use crate::events::WaterApplied;
 
 #[derive(Accounts)]
@@ -13,6 +14,14 @@ pub struct WaterTile<'info> {
         has_one = owner @ FarmingError::InvalidTileIndex
     )]
     pub player_account: Account<'info, PlayerAccount>,
+
+    /// Extra field to act on; omit to use the home farm
+    #[account(
+        mut,
+        seeds = [FIELD_SEED, authority.key().as_ref(), &[field.field_id]],
+        bump = field.bump
+    )]
+    pub field: Option<Account<'info, Field>>,
     
     #[account(signer)]
     pub authority: Signer<'info>,
@@ -23,11 +32,12 @@ pub struct WaterTile<'info> {
 }
 
 pub fn handler(ctx: Context<WaterTile>, plot_index: u8) -> Result<()> {
-    let player_account = &mut ctx.accounts.player_account;
+    let player_account: &mut PlayerAccount = &mut ctx.accounts.player_account;
+    let (farm, field_id) = select_farm(&mut player_account.farm, ctx.accounts.field.as_deref_mut());
     let clock = &ctx.accounts.clock;
     
     // Validate plot index
-    if plot_index >= 25 {
+    if plot_index as usize >= farm.tile_count() {
         return Err(FarmingError::InvalidPlotIndex.into());
     }
     
@@ -35,31 +45,35 @@ pub fn handler(ctx: Context<WaterTile>, plot_index: u8) -> Result<()> {
     if player_account.watering_can_uses == 0 {
         return Err(FarmingError::InsufficientToolUses.into());
     }
+    let can = player_account.tools[ToolKind::WateringCan as usize];
+    require!(!can.is_broken(), FarmingError::ToolBroken);
     
     let current_timestamp = clock.unix_timestamp;
     
     // Check if plot was watered less than 1 hour ago
-    let last_watered_time = player_account.last_watered[plot_index as usize];
+    let last_watered_time = farm.last_watered[plot_index as usize];
     if last_watered_time > 0 && current_timestamp - last_watered_time < 3600 {
         return Err(FarmingError::WateringTooFrequent.into());
     }
     
     // Get current water level
-    let current_water = player_account.water_levels[plot_index as usize] as u16;
+    let current_water = farm.water_levels[plot_index as usize] as u16;
     
-    // Add 50 water, capped at 100
-    let new_water_level = std::cmp::min(current_water + 50, 100) as u8;
+    // Add the can tier's water amount, capped at 100
+    let new_water_level = std::cmp::min(current_water + can.tier.water_amount() as u16, 100) as u8;
     
     // Update water levels
-    player_account.water_levels[plot_index as usize] = new_water_level;
-    player_account.last_watered[plot_index as usize] = current_timestamp;
+    farm.water_levels[plot_index as usize] = new_water_level;
+    farm.last_watered[plot_index as usize] = current_timestamp;
     
     // Decrement watering can uses
     player_account.watering_can_uses = player_account.watering_can_uses.saturating_sub(1);
+    player_account.tools[ToolKind::WateringCan as usize].wear();
     
     // Emit event
     emit!(WaterApplied {
         player: ctx.accounts.authority.key(),
+        field_id,
         plot_index,
         new_water_level,
         timestamp: current_timestamp,
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{GAME_CONFIG_SEED, MARKETPLACE_SEED, PLAYER_SEED};
+use crate::errors::FarmingError;
+use crate::events::MarketplaceFeesWithdrawn;
+use crate::state::{GameConfig, Marketplace, PlayerAccount};
+
+#[derive(Accounts)]
+pub struct WithdrawMarketplaceFees<'info> {
+    #[account(
+        mut,
+        seeds = [MARKETPLACE_SEED],
+        bump = marketplace.bump
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+
+    #[account(
+        seeds = [GAME_CONFIG_SEED],
+        bump = game_config.bump,
+        constraint = game_config.treasury == authority.key() @ FarmingError::UnauthorizedTreasury
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    /// Treasury's own player account receives the collected coins
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, authority.key().as_ref()],
+        bump = player_account.bump
+    )]
+    pub player_account: Account<'info, PlayerAccount>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<WithdrawMarketplaceFees>) -> Result<()> {
+    let marketplace = &mut ctx.accounts.marketplace;
+    let amount = marketplace.fees_collected;
+    marketplace.fees_collected = 0;
+
+    let player_account = &mut ctx.accounts.player_account;
+    player_account.coins = player_account.coins.saturating_add(amount);
+
+    emit!(MarketplaceFeesWithdrawn {
+        treasury: ctx.accounts.authority.key(),
+        amount,
+    });
+
+    msg!("Withdrew {} coins of marketplace fees", amount);
+    Ok(())
+}
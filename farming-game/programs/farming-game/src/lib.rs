@@ -7,6 +7,7 @@ pub mod instructions;
 pub mod state;
 
 use instructions::*;
+use state::{GameConfigUpdate, Recipe, TradeTerms};
 
 declare_id!("8NND7mQn5q7UQcrVrzrQfsHwYruqnQshMjFuwq4WBaHR");
 
@@ -58,8 +59,8 @@ pub mod farming_game {
         instructions::water_tile::handler(ctx, plot_index)
     }
 
-    pub fn use_fertilizer(ctx: Context<UseFertilizer>, plot_index: u8) -> Result<()> {
-        instructions::use_fertilizer::handler(ctx, plot_index)
+    pub fn use_fertilizer(ctx: Context<UseFertilizer>, plot_index: u8, fertilizer_type: u8) -> Result<()> {
+        instructions::use_fertilizer::handler(ctx, plot_index, fertilizer_type)
     }
 
     pub fn refill_watering_can(ctx: Context<RefillWateringCan>) -> Result<()> {
@@ -70,8 +71,8 @@ pub mod farming_game {
         instructions::buy_tool::handler(ctx, tool_type, quantity)
     }
 
-    pub fn gather_resource(ctx: Context<GatherResource>, resource_type: u8, amount: u16) -> Result<()> {
-        instructions::gather_resource::handler(ctx, resource_type, amount)
+    pub fn gather_resource(ctx: Context<GatherResource>, amount: u16) -> Result<()> {
+        instructions::gather_resource::handler(ctx, amount)
     }
 
     pub fn craft_item(ctx: Context<CraftItem>, item_id: u8) -> Result<()> {
@@ -89,4 +90,131 @@ pub mod farming_game {
     pub fn check_patterns(ctx: Context<CheckPatterns>, plot_index: u8) -> Result<()> {
         instructions::check_patterns::handler(ctx, plot_index)
     }
+
+    pub fn export_crafted_item(ctx: Context<ExportCraftedItem>, asset_id: u64, item_id: u8) -> Result<()> {
+        instructions::export_crafted_item::handler(ctx, asset_id, item_id)
+    }
+
+    pub fn transfer_crafted_asset(ctx: Context<TransferCraftedAsset>, new_owner: Pubkey) -> Result<()> {
+        instructions::transfer_crafted_asset::handler(ctx, new_owner)
+    }
+
+    pub fn import_crafted_asset(ctx: Context<ImportCraftedAsset>) -> Result<()> {
+        instructions::import_crafted_asset::handler(ctx)
+    }
+
+    pub fn create_trade(
+        ctx: Context<CreateTrade>,
+        offer_id: u64,
+        offered: TradeTerms,
+        requested: TradeTerms,
+        duration: i64,
+        taker: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::create_trade::handler(ctx, offer_id, offered, requested, duration, taker)
+    }
+
+    pub fn accept_trade(ctx: Context<AcceptTrade>) -> Result<()> {
+        instructions::accept_trade::handler(ctx)
+    }
+
+    pub fn cancel_trade(ctx: Context<CancelTrade>) -> Result<()> {
+        instructions::cancel_trade::handler(ctx)
+    }
+
+    pub fn update_game_config(ctx: Context<UpdateGameConfig>, update: GameConfigUpdate) -> Result<()> {
+        instructions::update_game_config::handler(ctx, update)
+    }
+
+    pub fn initialize_marketplace(ctx: Context<InitializeMarketplace>) -> Result<()> {
+        instructions::initialize_marketplace::handler(ctx)
+    }
+
+    pub fn list_resource(ctx: Context<ListResource>, resource_type: u8, quantity: u16, price_per_unit: u64) -> Result<()> {
+        instructions::list_resource::handler(ctx, resource_type, quantity, price_per_unit)
+    }
+
+    pub fn buy_partial(ctx: Context<BuyPartial>, quantity: u16) -> Result<()> {
+        instructions::buy_partial::handler(ctx, quantity)
+    }
+
+    pub fn delist_resource(ctx: Context<DelistResource>) -> Result<()> {
+        instructions::delist_resource::handler(ctx)
+    }
+
+    pub fn withdraw_marketplace_fees(ctx: Context<WithdrawMarketplaceFees>) -> Result<()> {
+        instructions::withdraw_marketplace_fees::handler(ctx)
+    }
+
+    pub fn expand_farm(ctx: Context<ExpandFarm>) -> Result<()> {
+        instructions::expand_farm::handler(ctx)
+    }
+
+    pub fn buy_field(ctx: Context<BuyField>, field_id: u8) -> Result<()> {
+        instructions::buy_field::handler(ctx, field_id)
+    }
+
+    pub fn expand_field(ctx: Context<ExpandField>) -> Result<()> {
+        instructions::expand_field::handler(ctx)
+    }
+
+    pub fn detect_all_patterns(ctx: Context<DetectAllPatterns>) -> Result<()> {
+        instructions::detect_all_patterns::handler(ctx)
+    }
+
+    pub fn fill_compost_bin(ctx: Context<FillCompostBin>, bin_index: u8, crop_waste: u16, fiber: u16) -> Result<()> {
+        instructions::fill_compost_bin::handler(ctx, bin_index, crop_waste, fiber)
+    }
+
+    pub fn create_resource_node(ctx: Context<CreateResourceNode>, region: Pubkey, node_id: u32, kind: u8) -> Result<()> {
+        instructions::create_resource_node::handler(ctx, region, node_id, kind)
+    }
+
+    pub fn upgrade_tool(ctx: Context<UpgradeTool>, tool_kind: u8) -> Result<()> {
+        instructions::upgrade_tool::handler(ctx, tool_kind)
+    }
+
+    pub fn repair_tool(ctx: Context<RepairTool>, tool_kind: u8) -> Result<()> {
+        instructions::repair_tool::handler(ctx, tool_kind)
+    }
+
+    pub fn initialize_recipe_registry(ctx: Context<InitializeRecipeRegistry>) -> Result<()> {
+        instructions::initialize_recipe_registry::handler(ctx)
+    }
+
+    pub fn update_recipe(ctx: Context<UpdateRecipe>, item_id: u8, recipe: Recipe) -> Result<()> {
+        instructions::update_recipe::handler(ctx, item_id, recipe)
+    }
+
+    pub fn unlock_recipe(ctx: Context<UnlockRecipe>, item_id: u8) -> Result<()> {
+        instructions::unlock_recipe::handler(ctx, item_id)
+    }
+
+    pub fn rush_crafting(ctx: Context<RushCrafting>) -> Result<()> {
+        instructions::rush_crafting::handler(ctx)
+    }
+
+    pub fn claim_quest(ctx: Context<ClaimQuest>, quest_index: u8) -> Result<()> {
+        instructions::claim_quest::handler(ctx, quest_index)
+    }
+
+    pub fn initialize_season_leaderboard(ctx: Context<InitializeSeasonLeaderboard>) -> Result<()> {
+        instructions::initialize_season_leaderboard::handler(ctx)
+    }
+
+    pub fn claim_season_prize(ctx: Context<ClaimSeasonPrize>) -> Result<()> {
+        instructions::claim_season_prize::handler(ctx)
+    }
+
+    pub fn migrate_game_config(ctx: Context<MigrateGameConfig>) -> Result<()> {
+        instructions::migrate_game_config::handler(ctx)
+    }
+
+    pub fn fund_season_prize_pool(ctx: Context<FundSeasonPrizePool>, amount: u64) -> Result<()> {
+        instructions::fund_season_prize_pool::handler(ctx, amount)
+    }
+
+    pub fn migrate_season_state(ctx: Context<MigrateSeasonState>) -> Result<()> {
+        instructions::migrate_season_state::handler(ctx)
+    }
 }
@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::ACHIEVEMENT_WHEAT_TARGET;
+use crate::events::AchievementUnlocked;
+use crate::state::crop::CropType;
+use crate::state::inventory::ItemId;
+use crate::state::player::PlayerAccount;
+use crate::state::recipes::CraftableItem;
+use crate::state::synergy::PatternType;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Achievement {
+    FirstHarvest = 0,        // Harvest any crop
+    WheatFarmer = 1,         // Harvest wheat ACHIEVEMENT_WHEAT_TARGET times
+    PatternMaster = 2,       // Discover the eight classic PatternTypes
+    PerimeterDefender = 3,   // Harvest a tile protected by PerimeterDefense
+    MasterCrafter = 4,       // Craft every CraftableItem, intermediates included, at least once
+}
+
+impl Achievement {
+    /// Rewards: (coins, optional item and amount)
+    pub fn reward(&self) -> (u64, Option<(ItemId, u16)>) {
+        match self {
+            Achievement::FirstHarvest => (25, Some((ItemId::Seeds, 5))),
+            Achievement::WheatFarmer => (200, None),
+            Achievement::PatternMaster => (500, None),
+            Achievement::PerimeterDefender => (100, Some((ItemId::Stone, 10))),
+            Achievement::MasterCrafter => (300, Some((ItemId::Plank, 10))),
+        }
+    }
+}
+
+/// Per-player achievement state: unlocked badges plus the counters behind them
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AchievementProgress {
+    pub unlocked: u32,              // Bit per Achievement
+    pub wheat_harvested: u16,
+    pub patterns_discovered: u16,   // Bit per PatternType seen at harvest
+    pub items_crafted: u16,         // Bit per CraftableItem crafted
+}
+
+impl AchievementProgress {
+    pub const SPACE: usize = 4 // unlocked
+        + 2 // wheat_harvested
+        + 2 // patterns_discovered
+        + 2; // items_crafted
+
+    /// PatternMaster covers only the eight classic patterns. The later geometric ones
+    /// (FullBlock, MirrorSymmetry, ...) are still recorded but need near-complete farms.
+    const CLASSIC_PATTERNS: u16 = (1 << PatternType::CLASSIC_COUNT) - 1;
+    /// MasterCrafter covers all ten recipes on purpose: Plank, Brick and Rope are
+    /// needed for fences and sprinklers anyway, so a crafter meets them on the way.
+    const ALL_ITEMS: u16 = (1 << CraftableItem::ALL.len()) - 1;
+
+    pub fn is_unlocked(&self, achievement: Achievement) -> bool {
+        self.unlocked & (1 << achievement as u8) != 0
+    }
+
+    /// Mark an achievement unlocked, returning it only the first time
+    fn unlock(&mut self, achievement: Achievement, earned: bool) -> Option<Achievement> {
+        if !earned || self.is_unlocked(achievement) {
+            return None;
+        }
+        self.unlocked |= 1 << achievement as u8;
+        Some(achievement)
+    }
+
+    /// Update counters for a harvest; returns newly unlocked achievements
+    pub fn record_harvest(&mut self, crop_type: u8, patterns: &[PatternType]) -> Vec<Achievement> {
+        if crop_type == CropType::Wheat as u8 {
+            self.wheat_harvested = self.wheat_harvested.saturating_add(1);
+        }
+        for pattern in patterns {
+            self.patterns_discovered |= 1 << *pattern as u8;
+        }
+
+        [
+            self.unlock(Achievement::FirstHarvest, true),
+            self.unlock(Achievement::WheatFarmer, self.wheat_harvested >= ACHIEVEMENT_WHEAT_TARGET),
+            self.unlock(
+                Achievement::PatternMaster,
+                self.patterns_discovered & Self::CLASSIC_PATTERNS == Self::CLASSIC_PATTERNS,
+            ),
+            self.unlock(
+                Achievement::PerimeterDefender,
+                patterns.contains(&PatternType::PerimeterDefense),
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// Update counters for a finished craft; returns newly unlocked achievements
+    pub fn record_craft(&mut self, item: CraftableItem) -> Vec<Achievement> {
+        self.items_crafted |= 1 << item as u8;
+        self.unlock(Achievement::MasterCrafter, self.items_crafted == Self::ALL_ITEMS)
+            .into_iter()
+            .collect()
+    }
+}
+
+/// Pay rewards and emit AchievementUnlocked for each newly unlocked achievement
+pub fn grant_achievements(player_account: &mut PlayerAccount, player: Pubkey, unlocked: &[Achievement]) {
+    for achievement in unlocked {
+        let (coins, item) = achievement.reward();
+        player_account.coins = player_account.coins.saturating_add(coins);
+        let item_amount = item.map_or(0, |(item_id, amount)| player_account.inventory.add_capped(item_id, amount));
+
+        emit!(AchievementUnlocked {
+            player,
+            achievement: *achievement as u8,
+            coin_reward: coins,
+            item_reward: item.map(|(item_id, _)| item_id as u8),
+            item_amount,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_harvest_unlocks_once() {
+        let mut progress = AchievementProgress::default();
+        assert_eq!(progress.record_harvest(CropType::Carrot as u8, &[]), vec![Achievement::FirstHarvest]);
+        assert!(progress.record_harvest(CropType::Carrot as u8, &[]).is_empty());
+    }
+
+    #[test]
+    fn wheat_badge_needs_the_target_count() {
+        let mut progress = AchievementProgress::default();
+        for _ in 1..ACHIEVEMENT_WHEAT_TARGET {
+            assert!(!progress.record_harvest(CropType::Wheat as u8, &[]).contains(&Achievement::WheatFarmer));
+        }
+        assert_eq!(progress.record_harvest(CropType::Wheat as u8, &[]), vec![Achievement::WheatFarmer]);
+    }
+
+    #[test]
+    fn pattern_master_needs_every_classic_pattern() {
+        let mut progress = AchievementProgress::default();
+        let all: Vec<PatternType> = (0..PatternType::COUNT as u8).map(|p| PatternType::from_u8(p).unwrap()).collect();
+        let (classic, extended) = all.split_at(PatternType::CLASSIC_COUNT);
+        assert_eq!(classic.last(), Some(&PatternType::RotationSequence));
+
+        // The later patterns alone and all but one classic pattern are not enough
+        progress.record_harvest(1, extended);
+        assert!(progress.record_harvest(1, &classic[..classic.len() - 1]).contains(&Achievement::PerimeterDefender));
+        assert!(!progress.is_unlocked(Achievement::PatternMaster));
+        assert_eq!(progress.record_harvest(1, &classic[classic.len() - 1..]), vec![Achievement::PatternMaster]);
+
+        // MirrorSymmetry is not required
+        let mut progress = AchievementProgress::default();
+        assert!(progress.record_harvest(1, classic).contains(&Achievement::PatternMaster));
+    }
+
+    #[test]
+    fn master_crafter_needs_every_item() {
+        assert_eq!(CraftableItem::ALL.len(), 10);
+        let mut progress = AchievementProgress::default();
+        let (last, rest) = CraftableItem::ALL.split_last().unwrap();
+        for item in rest {
+            assert!(progress.record_craft(*item).is_empty());
+        }
+        assert_eq!(progress.record_craft(*last), vec![Achievement::MasterCrafter]);
+    }
+}
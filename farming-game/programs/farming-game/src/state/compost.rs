@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    COMPOST_BIN_CAPACITY, COMPOST_BIN_STORAGE_CAP, COMPOST_FILL_PER_UNIT, COMPOST_SECONDS_PER_UNIT,
+};
+
+/// State of a single compost bin. Organic inputs raise `fill`; over time the bin
+/// turns fill into compost, which waits in `stored` until collected.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CompostBin {
+    pub fill: u16,          // Organic matter waiting to be composted
+    pub progress: i64,      // Seconds of composting toward the next unit
+    pub stored: u16,        // Finished compost waiting to be collected
+    pub last_update: i64,   // Last time progress was settled
+}
+
+impl CompostBin {
+    pub const SPACE: usize = 2 // fill
+        + 8 // progress
+        + 2 // stored
+        + 8; // last_update
+
+    /// A bin works only with enough input and room to store the result
+    pub fn is_active(&self) -> bool {
+        self.fill >= COMPOST_FILL_PER_UNIT && self.stored < COMPOST_BIN_STORAGE_CAP
+    }
+
+    /// Room left for new inputs
+    pub fn free_capacity(&self) -> u16 {
+        COMPOST_BIN_CAPACITY.saturating_sub(self.fill)
+    }
+
+    /// Settle composting up to `current_time`. Partial progress toward the next
+    /// unit carries over; time spent idle (empty or full) does not count.
+    pub fn update(&mut self, current_time: i64) {
+        let elapsed = current_time.saturating_sub(self.last_update).max(0);
+        self.last_update = current_time;
+
+        if !self.is_active() {
+            self.progress = 0;
+            return;
+        }
+
+        self.progress = self.progress.saturating_add(elapsed);
+        let by_time = (self.progress / COMPOST_SECONDS_PER_UNIT).min(u16::MAX as i64) as u16;
+        let by_fill = self.fill / COMPOST_FILL_PER_UNIT;
+        let by_storage = COMPOST_BIN_STORAGE_CAP - self.stored;
+        let units = by_time.min(by_fill).min(by_storage);
+
+        self.stored += units;
+        self.fill -= units * COMPOST_FILL_PER_UNIT;
+        self.progress -= units as i64 * COMPOST_SECONDS_PER_UNIT;
+
+        // Ran out of input or storage: the leftover time was spent idle
+        if !self.is_active() {
+            self.progress = 0;
+        }
+    }
+
+    /// Take all finished compost out of the bin
+    pub fn take_stored(&mut self) -> u16 {
+        std::mem::take(&mut self.stored)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = COMPOST_SECONDS_PER_UNIT;
+
+    #[test]
+    fn partial_days_carry_over() {
+        let mut bin = CompostBin { fill: 30, ..CompostBin::default() };
+
+        bin.update(DAY / 2);
+        assert_eq!((bin.stored, bin.progress), (0, DAY / 2));
+
+        bin.update(DAY + DAY / 4);
+        assert_eq!(bin.stored, 1);
+        assert_eq!(bin.fill, 20);
+        assert_eq!(bin.progress, DAY / 4);
+    }
+
+    #[test]
+    fn composting_stops_when_input_runs_out() {
+        let mut bin = CompostBin { fill: 25, ..CompostBin::default() };
+        bin.update(5 * DAY + 100);
+        assert_eq!(bin.stored, 2);
+        assert_eq!(bin.fill, 5);
+        // Time after the input ran out was idle and does not count later
+        assert_eq!(bin.progress, 0);
+    }
+
+    #[test]
+    fn a_full_bin_waits_for_collection() {
+        let mut bin = CompostBin { fill: COMPOST_BIN_CAPACITY, ..CompostBin::default() };
+        bin.update(DAY * (COMPOST_BIN_STORAGE_CAP as i64 + 3));
+        assert_eq!(bin.stored, COMPOST_BIN_STORAGE_CAP.min(COMPOST_BIN_CAPACITY / COMPOST_FILL_PER_UNIT));
+
+        let mut bin = CompostBin { fill: COMPOST_BIN_CAPACITY, stored: COMPOST_BIN_STORAGE_CAP, ..CompostBin::default() };
+        assert!(!bin.is_active());
+        bin.update(3 * DAY);
+        assert_eq!((bin.stored, bin.fill, bin.progress), (COMPOST_BIN_STORAGE_CAP, COMPOST_BIN_CAPACITY, 0));
+
+        // Collecting frees room and composting resumes from that point
+        assert_eq!(bin.take_stored(), COMPOST_BIN_STORAGE_CAP);
+        bin.update(4 * DAY - 1);
+        assert_eq!(bin.stored, 0);
+        bin.update(4 * DAY);
+        assert_eq!(bin.stored, 1);
+    }
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+/// A crafted structure exported out of a player's inventory.
+/// The asset lives in its own PDA so it can change hands between players
+/// and later be imported back into any player's inventory.
+/// Structures carry no wear state, so assets do not track durability.
+#[account]
+pub struct CraftedAsset {
+    pub owner: Pubkey,         // Current holder (only the owner can transfer or import)
+    pub minter: Pubkey,        // Player who exported the item (part of the PDA seeds)
+    pub asset_id: u64,         // Minter-chosen id, unique per minter
+    pub item_id: u8,           // CraftableItem id
+    pub exported_at: i64,
+    pub bump: u8,
+}
+
+impl CraftedAsset {
+    pub const SPACE: usize = 8 // discriminator
+        + 32 // owner
+        + 32 // minter
+        + 8 // asset_id
+        + 1 // item_id
+        + 8 // exported_at
+        + 1; // bump
+}
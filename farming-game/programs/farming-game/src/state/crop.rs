@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::CROP_TYPE_COUNT;
 use crate::errors::FarmingError;
+use crate::state::soil::{NutrientDemand, SoilProfile};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(u8)]
@@ -12,7 +14,39 @@ pub enum CropType {
     Lettuce = 5,
 }
 
+/// Botanical family used for multi-season rotation bonuses
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CropFamily {
+    Legume, // Nitrogen fixers that prepare the soil
+    Grain,  // Heavy feeders
+    Root,   // Break up soil after grains
+    Fruit,  // Rotation-neutral
+}
+
 impl CropType {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(CropType::Wheat),
+            2 => Some(CropType::Tomato),
+            3 => Some(CropType::Corn),
+            4 => Some(CropType::Carrot),
+            5 => Some(CropType::Lettuce),
+            _ => None,
+        }
+    }
+
+    /// Player level needed to plant this crop. Fixed in code like the rest of the crop
+    /// table; only recipe gates are admin-tunable (see `Recipe::required_level`).
+    pub fn required_level(&self) -> u8 {
+        match self {
+            CropType::Wheat => 1,
+            CropType::Lettuce => 1,
+            CropType::Carrot => 2,
+            CropType::Tomato => 3,
+            CropType::Corn => 5,
+        }
+    }
+
     pub fn get_config(&self) -> CropConfig {
         match self {
             CropType::Wheat => wheat_config(),
@@ -39,8 +73,13 @@ pub struct CropConfig {
     pub min_yield: u32,
     /// Fertility lost on harvest
     pub fertility_cost: u8,
-    /// True for nitrogen-fixing crops that restore soil
+    /// Nutrient levels needed for a full harvest; half of each is drained on harvest
+    pub nutrient_demand: NutrientDemand,
+    /// True for nitrogen-fixing crops. Their harvest leaves soil nitrogen for the next
+    /// non-restorative crop on the tile and its neighbours rather than raising fertility.
     pub is_restorative: bool,
+    /// Rotation family (legume → grain → root is the full cycle)
+    pub family: CropFamily,
     /// Number of visual growth stages
     pub growth_stages: u8,
     /// Which seasons crop can be planted (up to 4 seasons)
@@ -50,6 +89,11 @@ pub struct CropConfig {
     pub growth_rate_modifiers: [f32; 4],
     /// Yield multiplier per season [Spring, Summer, Fall, Winter]
     pub yield_modifiers: [f32; 4],
+    /// Companion matrix row: yield % change when a neighbour is crop type i
+    /// (index 0 unused). Negative values are antagonist pairings.
+    pub companion_yield: [i8; CROP_TYPE_COUNT + 1],
+    /// Water bonus granted per neighbouring crop type i (index 0 unused)
+    pub companion_water: [u8; CROP_TYPE_COUNT + 1],
 }
 
 impl CropConfig {
@@ -107,29 +151,35 @@ pub fn calculate_harvest_yield(
     let yield_loss = (yield_loss_potential.saturating_mul(time_into_decay as u64))
         .saturating_div(decay_window as u64) as u32;
 
-    let final_yield = (base_yield as u32).saturating_sub(yield_loss);
+    let final_yield = base_yield.saturating_sub(yield_loss);
 
     Ok(final_yield.max(min_yield))
 }
 
-/// Calculate fertility modifier for yield (40% minimum, 100% at full fertility)
-/// Returns percentage multiplier (40-100)
-pub fn calculate_fertility_modifier(fertility: u8) -> u32 {
+/// Calculate how suitable a tile's soil is for a crop (40% minimum, 100% at full
+/// fertility with every nutrient demand met). Returns percentage multiplier (40-100)
+pub fn calculate_soil_suitability(fertility: u8, soil: &SoilProfile, demand: &NutrientDemand) -> u32 {
     const MIN_MODIFIER: u32 = 40;
     const MAX_MODIFIER: u32 = 100;
 
-    let modifier = MIN_MODIFIER + ((fertility as u32) * (MAX_MODIFIER - MIN_MODIFIER)) / 100;
+    let fertility_pct = (fertility as u32).min(100);
+    let soil_score = fertility_pct * soil.nutrient_coverage(demand) / 100;
+
+    let modifier = MIN_MODIFIER + (soil_score * (MAX_MODIFIER - MIN_MODIFIER)) / 100;
     modifier.min(MAX_MODIFIER)
 }
 
-/// Calculate harvest yield with both time decay AND fertility modifier
-pub fn calculate_harvest_yield_with_fertility(
+/// Calculate harvest yield with both time decay AND soil suitability
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_harvest_yield_with_soil(
     time_since_mature: i64,
     base_yield: u32,
     optimal_window: i64,
     max_decay_time: i64,
     min_yield: u32,
     fertility: u8,
+    soil: &SoilProfile,
+    demand: &NutrientDemand,
 ) -> Result<u32> {
     let time_based_yield = calculate_harvest_yield(
         time_since_mature,
@@ -139,7 +189,7 @@ pub fn calculate_harvest_yield_with_fertility(
         min_yield,
     )?;
 
-    let fertility_modifier = calculate_fertility_modifier(fertility);
+    let fertility_modifier = calculate_soil_suitability(fertility, soil, demand);
     let final_yield = (time_based_yield as u64)
         .saturating_mul(fertility_modifier as u64)
         .saturating_div(100) as u32;
@@ -160,12 +210,16 @@ fn wheat_config() -> CropConfig {
         base_yield: 100,
         min_yield: 20,
         fertility_cost: 10,
+        nutrient_demand: NutrientDemand { nitrogen: 50, phosphorus: 30, potassium: 30 },
         is_restorative: false,
+        family: CropFamily::Grain,
         growth_stages: 4,
         valid_seasons: [0, 1, 3, 0],
         valid_seasons_count: 3,
         growth_rate_modifiers: [1.0, 0.8, 0.0, 1.2],
         yield_modifiers: [1.1, 0.9, 0.0, 1.0],
+        companion_yield: [0, 0, -5, 0, 10, 0],
+        companion_water: [0; CROP_TYPE_COUNT + 1],
     }
 }
 
@@ -180,12 +234,16 @@ fn tomato_config() -> CropConfig {
         base_yield: 300,
         min_yield: 60,
         fertility_cost: 15,
+        nutrient_demand: NutrientDemand { nitrogen: 40, phosphorus: 60, potassium: 60 },
         is_restorative: false,
+        family: CropFamily::Fruit,
         growth_stages: 4,
         valid_seasons: [1, 0, 0, 0],
         valid_seasons_count: 1,
         growth_rate_modifiers: [0.0, 1.0, 0.0, 0.0],
         yield_modifiers: [0.0, 1.0, 0.0, 0.0],
+        companion_yield: [0, -5, 0, -10, 10, 5],
+        companion_water: [0; CROP_TYPE_COUNT + 1],
     }
 }
 
@@ -200,12 +258,16 @@ fn corn_config() -> CropConfig {
         base_yield: 500,
         min_yield: 100,
         fertility_cost: 20,
+        nutrient_demand: NutrientDemand { nitrogen: 70, phosphorus: 40, potassium: 40 },
         is_restorative: false,
+        family: CropFamily::Grain,
         growth_stages: 4,
         valid_seasons: [1, 0, 0, 0],
         valid_seasons_count: 1,
         growth_rate_modifiers: [0.0, 1.0, 0.0, 0.0],
         yield_modifiers: [0.0, 1.0, 0.0, 0.0],
+        companion_yield: [0, 0, -10, 0, 0, 5],
+        companion_water: [0, 0, 0, 0, 0, 5],
     }
 }
 
@@ -220,12 +282,16 @@ fn carrot_config() -> CropConfig {
         base_yield: 150,
         min_yield: 30,
         fertility_cost: 5,
+        nutrient_demand: NutrientDemand { nitrogen: 20, phosphorus: 40, potassium: 60 },
         is_restorative: true,
+        family: CropFamily::Root,
         growth_stages: 3,
         valid_seasons: [0, 1, 2, 3],
         valid_seasons_count: 4,
         growth_rate_modifiers: [1.0, 1.0, 1.1, 1.0],
         yield_modifiers: [1.0, 1.0, 1.0, 1.0],
+        companion_yield: [0, 10, 10, 0, 0, 5],
+        companion_water: [0; CROP_TYPE_COUNT + 1],
     }
 }
 
@@ -240,11 +306,15 @@ fn lettuce_config() -> CropConfig {
         base_yield: 80,
         min_yield: 16,
         fertility_cost: 5,
+        nutrient_demand: NutrientDemand { nitrogen: 30, phosphorus: 20, potassium: 20 },
         is_restorative: true,
+        family: CropFamily::Legume,
         growth_stages: 3,
         valid_seasons: [0, 1, 2, 0],
         valid_seasons_count: 3,
         growth_rate_modifiers: [1.1, 0.8, 1.0, 0.0],
         yield_modifiers: [1.0, 0.9, 1.0, 0.0],
+        companion_yield: [0, 0, 5, 5, 5, 0],
+        companion_water: [0, 0, 0, 5, 0, 0],
     }
 }
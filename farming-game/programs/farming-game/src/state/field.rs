@@ -0,0 +1,298 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    DEFAULT_PLAYER_FERTILITY, EXPANSION_COIN_COST, EXPANSION_STONE_COST, EXPANSION_WOOD_COST, FIELD_BASE_COST, GRID_SIZE, HOME_FIELD_ID,
+    NITROGEN_BOOST_ADJACENT, NITROGEN_BOOST_TILE,
+};
+use crate::state::player::FarmTile;
+use crate::state::soil::SoilProfile;
+
+/// A square farm grid: tiles, soil moisture and watering history.
+/// Embedded in PlayerAccount (the home farm) and in every extra Field.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct FarmGrid {
+    pub grid_size: u8,                 // Grid is grid_size x grid_size (starts at 5x5)
+    pub tiles: Vec<FarmTile>,          // Row-major, grid_size * grid_size entries
+    pub water_levels: Vec<u8>,         // Soil moisture per plot (0-100%)
+    pub last_watered: Vec<i64>,        // Timestamp of last watering per plot
+    pub last_water_decay_check: i64,   // Last timestamp water decay was applied
+}
+
+impl FarmGrid {
+    /// Serialized size of a `grid_size` x `grid_size` grid
+    pub const fn space(grid_size: u8) -> usize {
+        let tile_count = (grid_size as usize) * (grid_size as usize);
+        1 // grid_size
+            + 4 + (tile_count * FarmTile::SPACE) // tiles (Vec)
+            + 4 + tile_count // water_levels (Vec)
+            + 4 + (tile_count * 8) // last_watered (Vec)
+            + 8 // last_water_decay_check
+    }
+
+    /// Fresh grid of empty tiles at the given fertility, soil at 70% moisture
+    pub fn new(grid_size: u8, fertility: u8) -> Self {
+        let tile_count = (grid_size as usize) * (grid_size as usize);
+        let tile = FarmTile {
+            fertility,
+            soil: SoilProfile::initial(),
+            ..FarmTile::default()
+        };
+
+        Self {
+            grid_size,
+            tiles: vec![tile; tile_count],
+            water_levels: vec![70u8; tile_count],
+            last_watered: vec![0i64; tile_count],
+            last_water_decay_check: 0,
+        }
+    }
+
+    /// Number of tiles on the grid
+    pub fn tile_count(&self) -> usize {
+        self.tiles.len()
+    }
+
+    /// Convert a row-major tile index into (row, col) on the current grid
+    pub fn tile_coords(&self, tile_index: u8) -> (usize, usize) {
+        let grid_size = self.grid_size as usize;
+        (tile_index as usize / grid_size, tile_index as usize % grid_size)
+    }
+
+    /// Get plot at a specific (row, col) position on the grid
+    pub fn get_plot(&self, row: usize, col: usize) -> Option<&FarmTile> {
+        let grid_size = self.grid_size as usize;
+        if row >= grid_size || col >= grid_size {
+            return None;
+        }
+        Some(&self.tiles[row * grid_size + col])
+    }
+
+    /// Check if a plot at (row, col) has a mature crop ready to harvest
+    pub fn is_plot_harvestable(&self, row: usize, col: usize, current_time: i64) -> bool {
+        if let Some(plot) = self.get_plot(row, col) {
+            if plot.crop_type != 0 {
+                if let Ok(config) = crate::state::get_crop_config(plot.crop_type) {
+                    let mature_at = plot.planted_at + config.growth_time;
+                    return current_time >= mature_at;
+                }
+            }
+        }
+        false
+    }
+
+    /// Apply water decay to all plots based on days elapsed.
+    /// Soil with high moisture retention loses up to half as much.
+    pub fn apply_water_decay(&mut self, current_timestamp: i64) {
+        let seconds_per_day = 86400i64;
+        let days_elapsed = current_timestamp.saturating_sub(self.last_water_decay_check) / seconds_per_day;
+
+        if days_elapsed > 0 {
+            let decay_amount = (days_elapsed as u8).saturating_mul(5) as u16; // 5% per day
+
+            for (water_level, tile) in self.water_levels.iter_mut().zip(self.tiles.iter()) {
+                let retention = tile.soil.moisture_retention.min(100) as u16;
+                let tile_decay = decay_amount * (200 - retention) / 200;
+                *water_level = water_level.saturating_sub(tile_decay.min(u8::MAX as u16) as u8);
+            }
+
+            self.last_water_decay_check = current_timestamp;
+        }
+    }
+
+    /// Nitrogen fixing: a restorative harvest boosts this tile and its cardinal neighbours
+    pub fn deposit_nitrogen(&mut self, tile_index: u8) {
+        let grid_size = self.grid_size as usize;
+        let (row, col) = self.tile_coords(tile_index);
+        self.tiles[tile_index as usize].deposit_nitrogen(NITROGEN_BOOST_TILE);
+
+        let neighbors = [
+            (row.wrapping_sub(1), col),
+            (row + 1, col),
+            (row, col.wrapping_sub(1)),
+            (row, col + 1),
+        ];
+        for (r, c) in neighbors {
+            if r < grid_size && c < grid_size {
+                self.tiles[r * grid_size + c].deposit_nitrogen(NITROGEN_BOOST_ADJACENT);
+            }
+        }
+    }
+
+    /// Coins and resources needed to grow a grid to `new_grid_size`.
+    /// Each step beyond the starting 5x5 costs more than the last.
+    pub fn expansion_cost(new_grid_size: u8) -> (u64, [(u8, u16); 2]) {
+        let step = new_grid_size.saturating_sub(GRID_SIZE as u8) as u16;
+        let coins = EXPANSION_COIN_COST.saturating_mul(step as u64);
+        let resources = [
+            (0, EXPANSION_WOOD_COST.saturating_mul(step)),  // wood
+            (1, EXPANSION_STONE_COST.saturating_mul(step)), // stone
+        ];
+        (coins, resources)
+    }
+
+    /// Grow the grid by one row and one column, keeping every existing tile at
+    /// the same (row, col). New tiles start empty at the given fertility.
+    /// The owning account must already have room for `space(grid_size + 1)`.
+    pub fn expand(&mut self, fertility: u8) {
+        let old_size = self.grid_size as usize;
+        let mut expanded = Self::new(self.grid_size + 1, fertility);
+        let new_size = expanded.grid_size as usize;
+
+        for row in 0..old_size {
+            for col in 0..old_size {
+                let old_idx = row * old_size + col;
+                let new_idx = row * new_size + col;
+                expanded.tiles[new_idx] = self.tiles[old_idx];
+                expanded.water_levels[new_idx] = self.water_levels[old_idx];
+                expanded.last_watered[new_idx] = self.last_watered[old_idx];
+            }
+        }
+
+        expanded.last_water_decay_check = self.last_water_decay_check;
+        *self = expanded;
+    }
+}
+
+/// An extra farm plot owned by a player, separate from the home farm.
+/// Coins and inventory stay on the PlayerAccount; only the land lives here.
+#[account]
+pub struct Field {
+    pub owner: Pubkey,
+    pub field_id: u8,                  // 1..=MAX_FIELDS (0 is the home farm)
+    pub farm: FarmGrid,
+    pub bump: u8,
+}
+
+impl Field {
+    /// Account size for a field of `grid_size` x `grid_size` tiles
+    pub const fn space(grid_size: u8) -> usize {
+        8 // discriminator
+            + 32 // owner
+            + 1 // field_id
+            + FarmGrid::space(grid_size)
+            + 1 // bump
+    }
+
+    /// Hand the field to `owner` with a fresh 5x5 grid. Also used to take back a field
+    /// left behind by a closed PlayerAccount, which starts over like a new purchase.
+    pub fn open(&mut self, owner: Pubkey, field_id: u8, bump: u8) {
+        self.owner = owner;
+        self.field_id = field_id;
+        self.farm = FarmGrid::new(GRID_SIZE as u8, DEFAULT_PLAYER_FERTILITY);
+        self.bump = bump;
+    }
+
+    /// Coins needed to buy the field with this id (each extra field costs more)
+    pub fn purchase_cost(field_id: u8) -> u64 {
+        FIELD_BASE_COST.saturating_mul(field_id as u64)
+    }
+}
+
+/// Pick the farm an instruction acts on: the passed Field, or the home farm when none is given.
+/// Returns the grid together with its field id (0 = home farm).
+pub fn select_farm<'a>(home: &'a mut FarmGrid, field: Option<&'a mut Field>) -> (&'a mut FarmGrid, u8) {
+    match field {
+        Some(field) => (&mut field.farm, field.field_id),
+        None => (home, HOME_FIELD_ID),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expand_keeps_tiles_in_place() {
+        let mut grid = FarmGrid::new(GRID_SIZE as u8, 50);
+        grid.tiles[6].crop_type = 2; // (1, 1) on 5x5
+        grid.water_levels[24] = 10; // (4, 4)
+
+        grid.expand(40);
+        assert_eq!(grid.grid_size, GRID_SIZE as u8 + 1);
+        assert_eq!(grid.tile_count(), 36);
+        assert_eq!(grid.get_plot(1, 1).unwrap().crop_type, 2);
+        assert_eq!(grid.water_levels[4 * 6 + 4], 10);
+        assert_eq!(grid.get_plot(5, 5).unwrap().fertility, 40);
+        assert_eq!(grid.get_plot(0, 5).unwrap().crop_type, 0);
+    }
+
+    #[test]
+    fn space_matches_serialized_size() {
+        for grid_size in [GRID_SIZE as u8, 8] {
+            let mut grid = FarmGrid::new(grid_size, 50);
+            // Option fields take their full width only when set
+            for tile in grid.tiles.iter_mut() {
+                tile.planted_in_season = Some(0);
+            }
+            assert_eq!(grid.try_to_vec().unwrap().len(), FarmGrid::space(grid_size));
+        }
+    }
+
+    #[test]
+    fn each_expansion_step_costs_more() {
+        let (first_coins, first_resources) = FarmGrid::expansion_cost(GRID_SIZE as u8 + 1);
+        let (second_coins, second_resources) = FarmGrid::expansion_cost(GRID_SIZE as u8 + 2);
+        assert_eq!(first_coins, EXPANSION_COIN_COST);
+        assert_eq!(second_coins, EXPANSION_COIN_COST * 2);
+        assert_eq!(first_resources, [(0, EXPANSION_WOOD_COST), (1, EXPANSION_STONE_COST)]);
+        assert_eq!(second_resources[0].1, EXPANSION_WOOD_COST * 2);
+    }
+
+    #[test]
+    fn restorative_harvest_feeds_cardinal_neighbours() {
+        let mut grid = FarmGrid::new(GRID_SIZE as u8, 50);
+        grid.deposit_nitrogen(0); // corner: only right and below exist
+
+        assert_eq!(grid.tiles[0].nitrogen_bonus, NITROGEN_BOOST_TILE);
+        assert_eq!(grid.tiles[1].nitrogen_bonus, NITROGEN_BOOST_ADJACENT);
+        assert_eq!(grid.tiles[5].nitrogen_bonus, NITROGEN_BOOST_ADJACENT);
+        assert_eq!(grid.tiles[6].nitrogen_bonus, 0);
+        assert_eq!(grid.tiles.iter().filter(|tile| tile.nitrogen_bonus > 0).count(), 3);
+    }
+
+    #[test]
+    fn later_fields_cost_more() {
+        assert_eq!(Field::purchase_cost(1), FIELD_BASE_COST);
+        assert_eq!(Field::purchase_cost(3), FIELD_BASE_COST * 3);
+    }
+
+    #[test]
+    fn reopening_a_field_resets_its_grid() {
+        let owner = Pubkey::new_unique();
+        let mut field = Field {
+            owner,
+            field_id: 1,
+            farm: FarmGrid::new(GRID_SIZE as u8, 30),
+            bump: 0,
+        };
+        field.farm.expand(30);
+        field.farm.tiles[0].crop_type = 2;
+
+        field.open(owner, 1, 254);
+        assert_eq!(field.farm.grid_size, GRID_SIZE as u8);
+        assert_eq!(field.farm.tiles[0].crop_type, 0);
+        assert_eq!(field.farm.tiles[0].fertility, DEFAULT_PLAYER_FERTILITY);
+        assert_eq!(field.bump, 254);
+    }
+
+    #[test]
+    fn select_farm_defaults_to_the_home_farm() {
+        let mut home = FarmGrid::new(GRID_SIZE as u8, 50);
+        let mut field = Field {
+            owner: Pubkey::new_unique(),
+            field_id: 2,
+            farm: FarmGrid::new(GRID_SIZE as u8, 30),
+            bump: 0,
+        };
+
+        let (farm, field_id) = select_farm(&mut home, None);
+        assert_eq!(field_id, HOME_FIELD_ID);
+        assert_eq!(farm.tiles[0].fertility, 50);
+
+        let (farm, field_id) = select_farm(&mut home, Some(&mut field));
+        assert_eq!(field_id, 2);
+        farm.tiles[0].fertility = 10;
+        assert_eq!(field.farm.tiles[0].fertility, 10);
+        assert_eq!(home.tiles[0].fertility, 50);
+    }
+}
@@ -1,4 +1,12 @@
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::constants::{
+    DEFAULT_FALLOW_REGEN_CAP, DEFAULT_MARKETPLACE_FEE_BPS, DEFAULT_PATTERN_DIMINISHING_BPS, DEFAULT_PATTERN_YIELD_CAP_BPS,
+    DEFAULT_RUSH_COINS_PER_MINUTE, FALLOW_RESTORE_RATE, GAME_CONFIG_VERSION,
+};
+use crate::errors::FarmingError;
+use crate::state::synergy::StackingRules;
 
 #[account]
 pub struct GameConfig {
@@ -6,6 +14,13 @@ pub struct GameConfig {
     pub season_length: i64,
     pub base_fertility: u8,
     pub rotation_bonus: u8,
+    pub treasury: Pubkey,            // Receives marketplace fees
+    pub marketplace_fee_bps: u16,    // Fee charged on marketplace sales (basis points)
+    pub fallow_regen_interval: i64,  // Seconds of fallow per fertility point regained
+    pub fallow_regen_cap: u8,        // Fallow never raises fertility above this
+    pub rush_coins_per_minute: u64,  // Cost to skip each remaining minute of a crafting job
+    pub pattern_yield_cap_bps: u16,  // Per-tile cap on the stacked pattern multiplier
+    pub pattern_diminishing_bps: u16, // Weight each further pattern keeps relative to the previous one
     pub config_version: u8,
     pub bump: u8,
 }
@@ -16,6 +31,188 @@ impl GameConfig {
         + 8 // season_length
         + 1 // base_fertility
         + 1 // rotation_bonus
+        + 32 // treasury
+        + 2 // marketplace_fee_bps
+        + 8 // fallow_regen_interval
+        + 1 // fallow_regen_cap
+        + 8 // rush_coins_per_minute
+        + 2 // pattern_yield_cap_bps
+        + 2 // pattern_diminishing_bps
         + 1 // config_version
         + 1; // bump
+
+    /// Coins to finish a job with `remaining_seconds` left; partial minutes round up
+    pub fn rush_cost(&self, remaining_seconds: i64) -> u64 {
+        let minutes = (remaining_seconds.max(0) as u64).div_ceil(60);
+        minutes.saturating_mul(self.rush_coins_per_minute)
+    }
+
+    /// Pattern stacking rules shared by harvest_crop and check_patterns
+    pub fn stacking_rules(&self) -> StackingRules {
+        StackingRules::from_bps(self.pattern_yield_cap_bps, self.pattern_diminishing_bps)
+    }
+
+    /// Decode a config stored in any earlier layout and upgrade it to the current one.
+    /// Each version appended its fields before `config_version` and `bump`, which always
+    /// close the account; fields the stored version predates get their launch defaults.
+    ///   v1: authority, season_length, base_fertility, rotation_bonus
+    ///   v2: + treasury, marketplace_fee_bps
+    ///   v3: + fallow_regen_interval, fallow_regen_cap
+    ///   v4: + rush_coins_per_minute
+    ///   v5: + pattern_yield_cap_bps, pattern_diminishing_bps
+    pub fn from_versioned_bytes(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() >= 10 && data.starts_with(GameConfig::DISCRIMINATOR),
+            ErrorCode::AccountDidNotDeserialize
+        );
+        let (fields, tail) = data[8..].split_at(data.len() - 10);
+        let (version, bump) = (tail[0], tail[1]);
+        require!((1..=GAME_CONFIG_VERSION).contains(&version), FarmingError::UnsupportedConfigVersion);
+
+        let mut fields = fields;
+        let authority = Pubkey::deserialize(&mut fields)?;
+        let mut config = GameConfig {
+            authority,
+            season_length: i64::deserialize(&mut fields)?,
+            base_fertility: u8::deserialize(&mut fields)?,
+            rotation_bonus: u8::deserialize(&mut fields)?,
+            treasury: authority,
+            marketplace_fee_bps: DEFAULT_MARKETPLACE_FEE_BPS,
+            fallow_regen_interval: FALLOW_RESTORE_RATE,
+            fallow_regen_cap: DEFAULT_FALLOW_REGEN_CAP,
+            rush_coins_per_minute: DEFAULT_RUSH_COINS_PER_MINUTE,
+            pattern_yield_cap_bps: DEFAULT_PATTERN_YIELD_CAP_BPS,
+            pattern_diminishing_bps: DEFAULT_PATTERN_DIMINISHING_BPS,
+            config_version: GAME_CONFIG_VERSION,
+            bump,
+        };
+        if version >= 2 {
+            config.treasury = Pubkey::deserialize(&mut fields)?;
+            config.marketplace_fee_bps = u16::deserialize(&mut fields)?;
+        }
+        if version >= 3 {
+            config.fallow_regen_interval = i64::deserialize(&mut fields)?;
+            config.fallow_regen_cap = u8::deserialize(&mut fields)?;
+        }
+        if version >= 4 {
+            config.rush_coins_per_minute = u64::deserialize(&mut fields)?;
+        }
+        if version >= 5 {
+            config.pattern_yield_cap_bps = u16::deserialize(&mut fields)?;
+            config.pattern_diminishing_bps = u16::deserialize(&mut fields)?;
+        }
+        require!(fields.is_empty(), ErrorCode::AccountDidNotDeserialize);
+
+        Ok(config)
+    }
+}
+
+/// Admin update to GameConfig; `None` leaves a field unchanged
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct GameConfigUpdate {
+    pub treasury: Option<Pubkey>,
+    pub marketplace_fee_bps: Option<u16>,
+    pub fallow_regen_interval: Option<i64>,
+    pub fallow_regen_cap: Option<u8>,
+    pub rush_coins_per_minute: Option<u64>,
+    pub pattern_yield_cap_bps: Option<u16>,
+    pub pattern_diminishing_bps: Option<u16>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v1_bytes(authority: Pubkey) -> Vec<u8> {
+        let mut data = GameConfig::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(authority.as_ref());
+        data.extend_from_slice(&30i64.to_le_bytes()); // season_length
+        data.extend_from_slice(&[90, 10, 1, 254]); // base_fertility, rotation_bonus, config_version, bump
+        data
+    }
+
+    #[test]
+    fn v1_config_gets_current_defaults() {
+        let authority = Pubkey::new_unique();
+        let config = GameConfig::from_versioned_bytes(&v1_bytes(authority)).unwrap();
+
+        assert_eq!(config.authority, authority);
+        assert_eq!(config.season_length, 30);
+        assert_eq!(config.base_fertility, 90);
+        assert_eq!(config.treasury, authority);
+        assert_eq!(config.marketplace_fee_bps, DEFAULT_MARKETPLACE_FEE_BPS);
+        assert_eq!(config.rush_coins_per_minute, DEFAULT_RUSH_COINS_PER_MINUTE);
+        assert_eq!(config.stacking_rules(), StackingRules::default());
+        assert_eq!(config.config_version, GAME_CONFIG_VERSION);
+        assert_eq!(config.bump, 254);
+    }
+
+    #[test]
+    fn v2_config_keeps_marketplace_fields() {
+        let authority = Pubkey::new_unique();
+        let treasury = Pubkey::new_unique();
+        let mut data = v1_bytes(authority);
+        let tail = data.split_off(data.len() - 2);
+        data.extend_from_slice(treasury.as_ref());
+        data.extend_from_slice(&400u16.to_le_bytes());
+        data.extend_from_slice(&[2, tail[1]]);
+
+        let config = GameConfig::from_versioned_bytes(&data).unwrap();
+        assert_eq!(config.treasury, treasury);
+        assert_eq!(config.marketplace_fee_bps, 400);
+        assert_eq!(config.fallow_regen_interval, FALLOW_RESTORE_RATE);
+    }
+
+    #[test]
+    fn current_config_round_trips() {
+        let config = GameConfig {
+            authority: Pubkey::new_unique(),
+            season_length: 7,
+            base_fertility: 80,
+            rotation_bonus: 5,
+            treasury: Pubkey::new_unique(),
+            marketplace_fee_bps: 100,
+            fallow_regen_interval: 60,
+            fallow_regen_cap: 70,
+            rush_coins_per_minute: 9,
+            pattern_yield_cap_bps: 15_000,
+            pattern_diminishing_bps: 2_500,
+            config_version: GAME_CONFIG_VERSION,
+            bump: 1,
+        };
+        let mut data = Vec::new();
+        config.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), GameConfig::SPACE);
+
+        let decoded = GameConfig::from_versioned_bytes(&data).unwrap();
+        assert_eq!(decoded.treasury, config.treasury);
+        assert_eq!(decoded.fallow_regen_cap, 70);
+        assert_eq!(decoded.rush_coins_per_minute, 9);
+        assert_eq!(decoded.stacking_rules().max_yield_multiplier, 1.5);
+        assert_eq!(decoded.stacking_rules().diminishing_factor, 0.25);
+    }
+
+    #[test]
+    fn rush_cost_rounds_partial_minutes_up() {
+        let config = GameConfig::from_versioned_bytes(&v1_bytes(Pubkey::new_unique())).unwrap();
+        let per_minute = DEFAULT_RUSH_COINS_PER_MINUTE;
+
+        assert_eq!(config.rush_cost(0), 0);
+        assert_eq!(config.rush_cost(-30), 0);
+        assert_eq!(config.rush_cost(1), per_minute);
+        assert_eq!(config.rush_cost(60), per_minute);
+        assert_eq!(config.rush_cost(61), 2 * per_minute);
+        assert_eq!(config.rush_cost(3600), 60 * per_minute);
+
+        let free = GameConfig { rush_coins_per_minute: 0, ..config };
+        assert_eq!(free.rush_cost(3600), 0);
+    }
+
+    #[test]
+    fn unknown_versions_are_rejected() {
+        let mut data = v1_bytes(Pubkey::new_unique());
+        let version = data.len() - 2;
+        data[version] = GAME_CONFIG_VERSION + 1;
+        assert!(GameConfig::from_versioned_bytes(&data).is_err());
+    }
 }
@@ -0,0 +1,270 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{INVENTORY_SLOTS, MAX_COMPOST_BINS, MAX_CROP_WASTE};
+use crate::errors::FarmingError;
+use crate::state::resources::ResourceType;
+
+/// Unified item ids for everything a player holds. Raw resources keep their `ResourceType` ids.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ItemId {
+    // Raw resources
+    Wood = 0,
+    Stone = 1,
+    Fiber = 2,
+    Seeds = 3,
+    // Intermediate goods
+    Plank = 4,
+    Brick = 5,
+    Rope = 6,
+    // Consumables
+    Fertilizer = 7,
+    Compost = 8,
+    PremiumFertilizer = 9,
+    PremiumSeeds = 10,
+    CropWaste = 11,
+    // Crafted structures
+    CompostBin = 12,
+    Scarecrow = 13,
+    Fence = 14,
+    Sprinkler = 15,
+    AdvancedTool = 16,
+}
+
+impl ItemId {
+    pub fn from_u8(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(ItemId::Wood),
+            1 => Ok(ItemId::Stone),
+            2 => Ok(ItemId::Fiber),
+            3 => Ok(ItemId::Seeds),
+            4 => Ok(ItemId::Plank),
+            5 => Ok(ItemId::Brick),
+            6 => Ok(ItemId::Rope),
+            7 => Ok(ItemId::Fertilizer),
+            8 => Ok(ItemId::Compost),
+            9 => Ok(ItemId::PremiumFertilizer),
+            10 => Ok(ItemId::PremiumSeeds),
+            11 => Ok(ItemId::CropWaste),
+            12 => Ok(ItemId::CompostBin),
+            13 => Ok(ItemId::Scarecrow),
+            14 => Ok(ItemId::Fence),
+            15 => Ok(ItemId::Sprinkler),
+            16 => Ok(ItemId::AdvancedTool),
+            _ => Err(error!(FarmingError::InvalidItemId)),
+        }
+    }
+
+    pub fn from_resource(resource: ResourceType) -> Self {
+        match resource {
+            ResourceType::Wood => ItemId::Wood,
+            ResourceType::Stone => ItemId::Stone,
+            ResourceType::Fiber => ItemId::Fiber,
+            ResourceType::Seeds => ItemId::Seeds,
+        }
+    }
+
+    /// Per-item stack limits; the single source for every inventory cap
+    pub fn max_stack_size(&self) -> u16 {
+        match self {
+            ItemId::Wood => 999,
+            ItemId::Stone => 999,
+            ItemId::Fiber => 500,
+            ItemId::Seeds => 500,
+            ItemId::Plank => 200,
+            ItemId::Brick => 200,
+            ItemId::Rope => 200,
+            ItemId::Fertilizer => 500,
+            ItemId::Compost => 500,
+            ItemId::PremiumFertilizer => 500,
+            ItemId::PremiumSeeds => 500,
+            ItemId::CropWaste => MAX_CROP_WASTE,
+            ItemId::CompostBin => MAX_COMPOST_BINS as u16,
+            ItemId::Scarecrow => 50,
+            ItemId::Fence => 50,
+            ItemId::Sprinkler => 50,
+            ItemId::AdvancedTool => 50,
+        }
+    }
+
+    /// Error returned when a player holds too few of this item
+    pub fn shortage_error(&self) -> FarmingError {
+        match self {
+            ItemId::Fertilizer | ItemId::PremiumFertilizer => FarmingError::InsufficientFertilizer,
+            ItemId::Compost => FarmingError::InsufficientCompost,
+            ItemId::CropWaste => FarmingError::InsufficientCropWaste,
+            ItemId::CompostBin
+            | ItemId::Scarecrow
+            | ItemId::Fence
+            | ItemId::Sprinkler
+            | ItemId::AdvancedTool => FarmingError::InsufficientCraftedItems,
+            _ => FarmingError::InsufficientResources,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ItemStack {
+    pub item_id: u8,
+    pub amount: u16,          // 0 = empty slot
+}
+
+impl ItemStack {
+    pub const SPACE: usize = 1 // item_id
+        + 2; // amount
+}
+
+/// Fixed-size item-stack inventory, one stack per item id
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Inventory {
+    pub slots: [ItemStack; INVENTORY_SLOTS],
+}
+
+impl Inventory {
+    pub const SPACE: usize = ItemStack::SPACE * INVENTORY_SLOTS;
+
+    /// Items every new player starts with
+    pub fn starter() -> Self {
+        let mut inventory = Self::default();
+        for (slot, (item, amount)) in inventory.slots.iter_mut().zip([
+            (ItemId::Wood, 10),
+            (ItemId::Stone, 5),
+            (ItemId::Fiber, 8),
+            (ItemId::Fertilizer, 5),
+        ]) {
+            *slot = ItemStack { item_id: item as u8, amount };
+        }
+        inventory
+    }
+
+    pub fn amount(&self, item: ItemId) -> u16 {
+        self.slots
+            .iter()
+            .find(|slot| slot.amount > 0 && slot.item_id == item as u8)
+            .map_or(0, |slot| slot.amount)
+    }
+
+    pub fn has(&self, item: ItemId, amount: u16) -> bool {
+        self.amount(item) >= amount
+    }
+
+    /// Room left for an item, counting a free slot if it has no stack yet
+    pub fn free_capacity(&self, item: ItemId) -> u16 {
+        let held = self.amount(item);
+        if held == 0 && !self.slots.iter().any(|slot| slot.amount == 0) {
+            return 0;
+        }
+        item.max_stack_size().saturating_sub(held)
+    }
+
+    pub fn add(&mut self, item: ItemId, amount: u16) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        let id = item as u8;
+        let index = self
+            .slots
+            .iter()
+            .position(|slot| slot.amount > 0 && slot.item_id == id)
+            .or_else(|| self.slots.iter().position(|slot| slot.amount == 0))
+            .ok_or(FarmingError::InventoryFull)?;
+
+        let slot = &mut self.slots[index];
+        let new_amount = slot.amount as u32 + amount as u32;
+        require!(
+            new_amount <= item.max_stack_size() as u32,
+            FarmingError::ResourceStackOverflow
+        );
+        slot.item_id = id;
+        slot.amount = new_amount as u16;
+        Ok(())
+    }
+
+    /// Add as much as fits under the stack limit, returning the amount credited
+    pub fn add_capped(&mut self, item: ItemId, amount: u16) -> u16 {
+        let credited = amount.min(self.free_capacity(item));
+        match self.add(item, credited) {
+            Ok(()) => credited,
+            Err(_) => 0,
+        }
+    }
+
+    pub fn remove(&mut self, item: ItemId, amount: u16) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        let slot = self
+            .slots
+            .iter_mut()
+            .find(|slot| slot.amount > 0 && slot.item_id == item as u8)
+            .ok_or(item.shortage_error())?;
+        require!(slot.amount >= amount, item.shortage_error());
+        slot.amount -= amount;
+        Ok(())
+    }
+
+    /// Add every (item_id, amount) pair
+    pub fn add_all(&mut self, gains: &[(u8, u16)]) -> Result<()> {
+        for &(item_id, amount) in gains {
+            self.add(ItemId::from_u8(item_id)?, amount)?;
+        }
+        Ok(())
+    }
+
+    /// Remove every (item_id, amount) pair
+    pub fn remove_all(&mut self, costs: &[(u8, u16)]) -> Result<()> {
+        for &(item_id, amount) in costs {
+            self.remove(ItemId::from_u8(item_id)?, amount)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_and_remove_share_one_stack() {
+        let mut inventory = Inventory::default();
+        inventory.add(ItemId::Plank, 5).unwrap();
+        inventory.add(ItemId::Plank, 3).unwrap();
+        assert_eq!(inventory.amount(ItemId::Plank), 8);
+        assert_eq!(inventory.slots.iter().filter(|slot| slot.amount > 0).count(), 1);
+
+        inventory.remove(ItemId::Plank, 8).unwrap();
+        assert!(!inventory.has(ItemId::Plank, 1));
+        assert!(inventory.remove(ItemId::Plank, 1).is_err());
+    }
+
+    #[test]
+    fn stack_limits_come_from_the_item_table() {
+        let mut inventory = Inventory::default();
+        let limit = ItemId::Fiber.max_stack_size();
+        inventory.add(ItemId::Fiber, limit).unwrap();
+        assert!(inventory.add(ItemId::Fiber, 1).is_err());
+
+        assert_eq!(inventory.add_capped(ItemId::Seeds, limit + 10), ItemId::Seeds.max_stack_size());
+        assert_eq!(inventory.add_capped(ItemId::Seeds, 1), 0);
+    }
+
+    #[test]
+    fn full_inventory_rejects_new_items() {
+        let mut inventory = Inventory::default();
+        for slot in inventory.slots.iter_mut() {
+            *slot = ItemStack { item_id: ItemId::Wood as u8, amount: 1 };
+        }
+        assert_eq!(inventory.free_capacity(ItemId::Rope), 0);
+        assert!(inventory.add(ItemId::Rope, 1).is_err());
+    }
+
+    #[test]
+    fn starter_inventory_matches_launch_items() {
+        let inventory = Inventory::starter();
+        assert_eq!(inventory.amount(ItemId::Wood), 10);
+        assert_eq!(inventory.amount(ItemId::Stone), 5);
+        assert_eq!(inventory.amount(ItemId::Fiber), 8);
+        assert_eq!(inventory.amount(ItemId::Fertilizer), 5);
+        assert_eq!(inventory.amount(ItemId::CompostBin), 0);
+    }
+}
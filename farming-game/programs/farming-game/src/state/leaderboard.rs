@@ -0,0 +1,359 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{BPS_DENOMINATOR, LEADERBOARD_SIZE, SEASON_PRIZE_SHARES_BPS};
+use crate::errors::FarmingError;
+use crate::events::SeasonLeaderboardFrozen;
+use crate::state::season::SeasonState;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LeaderboardEntry {
+    pub player: Pubkey,       // Default = empty slot
+    pub coins: u64,           // Coins earned from harvests this season
+    pub claimed: bool,
+}
+
+impl LeaderboardEntry {
+    pub const SPACE: usize = 32 // player
+        + 8 // coins
+        + 1; // claimed
+
+    pub fn is_empty(&self) -> bool {
+        self.player == Pubkey::default()
+    }
+}
+
+/// Top harvest earners for one season of one year, sorted by coins (highest first)
+#[account]
+pub struct SeasonLeaderboard {
+    pub season: u8,
+    pub year: u32,
+    pub entries: [LeaderboardEntry; LEADERBOARD_SIZE],
+    pub frozen: bool,         // Set once the season ends; no more updates, prizes claimable
+    pub prize_pool: u64,      // Coins still held for prizes (funded from marketplace fees)
+    pub prize_total: u64,     // Pool size when the board froze; prize shares are taken from this
+    pub opened: bool,         // Set by open(); a board fresh from init_if_needed is still zeroed
+    pub bump: u8,
+}
+
+impl SeasonLeaderboard {
+    pub const SPACE: usize = 8 // discriminator
+        + 1 // season
+        + 4 // year
+        + LeaderboardEntry::SPACE * LEADERBOARD_SIZE // entries
+        + 1 // frozen
+        + 8 // prize_pool
+        + 8 // prize_total
+        + 1 // opened
+        + 1; // bump
+
+    /// Set up an empty, unfunded board for `season` of `year`
+    pub fn open(&mut self, season: u8, year: u32, bump: u8) {
+        self.season = season;
+        self.year = year;
+        self.entries = [LeaderboardEntry::default(); LEADERBOARD_SIZE];
+        self.frozen = false;
+        self.prize_pool = 0;
+        self.prize_total = 0;
+        self.opened = true;
+        self.bump = bump;
+    }
+
+    /// Open the board unless an earlier call already did; an open board keeps its standings and pool
+    pub fn open_once(&mut self, season: u8, year: u32, bump: u8) {
+        if !self.opened {
+            self.open(season, year, bump);
+        }
+    }
+
+    /// Add coins to the pool while the season is still running
+    pub fn fund(&mut self, amount: u64) -> Result<()> {
+        require!(!self.frozen, FarmingError::LeaderboardFrozen);
+        self.prize_pool = self.prize_pool.checked_add(amount).ok_or(FarmingError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Lock the standings and the pool the prizes are shared from
+    pub fn freeze(&mut self) {
+        self.frozen = true;
+        self.prize_total = self.prize_pool;
+    }
+
+    /// Record a player's season total, keeping the board sorted. Returns the player's rank, if ranked.
+    pub fn submit(&mut self, player: Pubkey, coins: u64) -> Option<usize> {
+        let existing = self.entries.iter().position(|entry| entry.player == player);
+        let slot = match existing {
+            Some(index) if self.entries[index].coins >= coins => return Some(index),
+            Some(index) => index,
+            None => {
+                let last = LEADERBOARD_SIZE - 1;
+                let lowest = &self.entries[last];
+                if !lowest.is_empty() && lowest.coins >= coins {
+                    return None;
+                }
+                last
+            }
+        };
+
+        self.entries[slot] = LeaderboardEntry {
+            player,
+            coins,
+            claimed: false,
+        };
+
+        // Bubble the updated entry up past anyone it now out-earns
+        let mut rank = slot;
+        while rank > 0 && (self.entries[rank - 1].is_empty() || self.entries[rank - 1].coins < coins) {
+            self.entries.swap(rank - 1, rank);
+            rank -= 1;
+        }
+        Some(rank)
+    }
+
+    pub fn rank_of(&self, player: &Pubkey) -> Option<usize> {
+        self.entries.iter().position(|entry| !entry.is_empty() && entry.player == *player)
+    }
+
+    /// Prize for a rank: its share of the pool as it stood at the freeze
+    pub fn prize_for_rank(&self, rank: usize) -> u64 {
+        let share = SEASON_PRIZE_SHARES_BPS.get(rank).copied().unwrap_or(0) as u128;
+        (self.prize_total as u128 * share / BPS_DENOMINATOR as u128) as u64
+    }
+
+    /// Mark a rank's prize claimed and draw it from the pool. Shares of empty ranks stay in the pool.
+    pub fn pay_out(&mut self, rank: usize) -> Result<u64> {
+        require!(self.frozen, FarmingError::SeasonNotFinished);
+        let entry = self.entries.get_mut(rank).ok_or(FarmingError::NotOnLeaderboard)?;
+        require!(!entry.is_empty(), FarmingError::NotOnLeaderboard);
+        require!(!entry.claimed, FarmingError::SeasonPrizeAlreadyClaimed);
+        entry.claimed = true;
+
+        let prize = self.prize_for_rank(rank).min(self.prize_pool);
+        self.prize_pool -= prize;
+        Ok(prize)
+    }
+}
+
+/// Lock a finished season's standings so prizes can be claimed
+pub fn freeze_leaderboard(leaderboard: &mut SeasonLeaderboard) {
+    if leaderboard.frozen {
+        return;
+    }
+    leaderboard.freeze();
+
+    let winner = leaderboard.entries[0];
+    emit!(SeasonLeaderboardFrozen {
+        season: leaderboard.season,
+        year: leaderboard.year,
+        winner: winner.player,
+        winning_coins: winner.coins,
+    });
+    msg!("Season {} of year {} leaderboard frozen", leaderboard.season, leaderboard.year);
+}
+
+/// One in-game day passes. The following season's board is opened ahead of time, and once
+/// the current season has run its length its board freezes and play moves on to the next.
+/// Returns true when the season rolled over.
+pub fn advance_season_day(
+    season_state: &mut SeasonState,
+    current: &mut SeasonLeaderboard,
+    next: &mut SeasonLeaderboard,
+    next_bump: u8,
+) -> bool {
+    let (next_season, next_year) = season_state.next_season();
+    next.open_once(next_season, next_year, next_bump);
+
+    season_state.days_passed = season_state.days_passed.saturating_add(1);
+    let days_in_current_season = season_state.days_passed.saturating_sub(season_state.season_start_day);
+    if days_in_current_season < season_state.season_length() {
+        return false;
+    }
+
+    roll_over_season(season_state, current, next, next_season, next_year, next_bump);
+    true
+}
+
+/// Start `season` of `year` today: the outgoing board freezes and the incoming one opens
+pub fn roll_over_season(
+    season_state: &mut SeasonState,
+    outgoing: &mut SeasonLeaderboard,
+    incoming: &mut SeasonLeaderboard,
+    season: u8,
+    year: u32,
+    incoming_bump: u8,
+) {
+    freeze_leaderboard(outgoing);
+    incoming.open_once(season, year, incoming_bump);
+
+    season_state.current_season = season;
+    season_state.year = year;
+    season_state.season_start_day = season_state.days_passed;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board() -> SeasonLeaderboard {
+        let mut board = SeasonLeaderboard {
+            season: 0,
+            year: 0,
+            entries: [LeaderboardEntry::default(); LEADERBOARD_SIZE],
+            frozen: false,
+            prize_pool: 0,
+            prize_total: 0,
+            opened: false,
+            bump: 0,
+        };
+        board.open(1, 2, 255);
+        board
+    }
+
+    /// A board as init_if_needed creates it: zeroed and not opened
+    fn unopened() -> SeasonLeaderboard {
+        SeasonLeaderboard {
+            season: 0,
+            year: 0,
+            entries: [LeaderboardEntry::default(); LEADERBOARD_SIZE],
+            frozen: false,
+            prize_pool: 0,
+            prize_total: 0,
+            opened: false,
+            bump: 0,
+        }
+    }
+
+    fn season_state(current_season: u8, year: u32) -> SeasonState {
+        SeasonState {
+            current_season,
+            days_passed: 0,
+            season_start_day: 0,
+            year,
+            authority: Pubkey::new_unique(),
+            bump: 255,
+        }
+    }
+
+    fn player(id: u8) -> Pubkey {
+        Pubkey::new_from_array([id; 32])
+    }
+
+    #[test]
+    fn submissions_stay_sorted_and_update_in_place() {
+        let mut board = board();
+        assert_eq!(board.submit(player(1), 50), Some(0));
+        assert_eq!(board.submit(player(2), 80), Some(0));
+        assert_eq!(board.submit(player(1), 120), Some(0));
+        assert_eq!(board.rank_of(&player(2)), Some(1));
+        assert_eq!(board.entries.iter().filter(|entry| !entry.is_empty()).count(), 2);
+    }
+
+    #[test]
+    fn full_board_drops_the_lowest() {
+        let mut board = board();
+        for id in 1..=LEADERBOARD_SIZE as u8 {
+            board.submit(player(id), id as u64 * 10);
+        }
+        assert_eq!(board.submit(player(99), 5), None);
+        assert_eq!(board.submit(player(99), 15), Some(LEADERBOARD_SIZE - 1));
+        assert_eq!(board.rank_of(&player(1)), None);
+    }
+
+    #[test]
+    fn prize_shares_cover_the_pool() {
+        let mut board = board();
+        board.fund(10_000).unwrap();
+        board.freeze();
+        let total: u64 = (0..LEADERBOARD_SIZE).map(|rank| board.prize_for_rank(rank)).sum();
+        assert_eq!(total, board.prize_total);
+        assert_eq!(board.prize_for_rank(LEADERBOARD_SIZE), 0);
+    }
+
+    #[test]
+    fn boards_open_empty_and_unfunded() {
+        let board = board();
+        assert_eq!((board.season, board.year, board.bump), (1, 2, 255));
+        assert_eq!(board.prize_pool, 0);
+        assert!(board.entries.iter().all(LeaderboardEntry::is_empty));
+        assert_eq!(board.prize_for_rank(0), 0);
+    }
+
+    #[test]
+    fn advancing_twice_keeps_the_next_board() {
+        let mut state = season_state(0, 0);
+        let mut current = board();
+        let mut next = unopened();
+
+        assert!(!advance_season_day(&mut state, &mut current, &mut next, 254));
+        assert!(next.opened);
+        assert_eq!((next.season, next.year, next.bump), (1, 0, 254));
+        next.submit(player(1), 40);
+        next.fund(100).unwrap();
+
+        // The second call finds the board already open and leaves it alone
+        assert!(!advance_season_day(&mut state, &mut current, &mut next, 254));
+        assert_eq!(next.rank_of(&player(1)), Some(0));
+        assert_eq!(next.prize_pool, 100);
+        assert_eq!(state.days_passed, 2);
+        assert!(!current.frozen);
+    }
+
+    #[test]
+    fn last_day_of_the_season_freezes_and_rolls_over() {
+        let mut state = season_state(3, 0);
+        state.days_passed = state.season_length() - 1;
+        let mut current = board();
+        let mut next = unopened();
+
+        assert!(advance_season_day(&mut state, &mut current, &mut next, 254));
+        assert!(current.frozen);
+        assert_eq!((state.current_season, state.year), (0, 1));
+        assert_eq!((next.season, next.year), (0, 1));
+        assert_eq!(state.season_start_day, state.days_passed);
+    }
+
+    #[test]
+    fn harvests_after_set_season_land_on_the_target_board() {
+        let mut state = season_state(2, 0);
+        let mut current = board();
+        current.open(2, 0, 253);
+        current.submit(player(1), 70);
+        let mut target = unopened();
+
+        // Going back to Summer wraps into the next year
+        let (season, year) = state.set_season_target(1);
+        assert_eq!((season, year), (1, 1));
+        roll_over_season(&mut state, &mut current, &mut target, season, year, 254);
+
+        assert!(current.frozen);
+        assert_eq!(current.rank_of(&player(1)), Some(0));
+
+        // Harvest derives its board from the season state and needs it open and unfrozen
+        assert_eq!((target.season, target.year), (state.current_season, state.year));
+        assert!(target.opened && !target.frozen);
+        assert_eq!(target.submit(player(1), 15), Some(0));
+        assert_eq!(current.entries[0].coins, 70);
+    }
+
+    #[test]
+    fn payouts_draw_down_the_frozen_pool() {
+        let mut board = board();
+        board.submit(player(1), 500);
+        board.submit(player(2), 300);
+        board.fund(6_000).unwrap();
+        board.fund(4_000).unwrap();
+
+        assert!(board.pay_out(0).is_err());
+        board.freeze();
+        assert!(board.fund(1).is_err());
+
+        assert_eq!(board.pay_out(1).unwrap(), 2_000);
+        assert_eq!(board.prize_pool, 8_000);
+        // Later claims still get their full share of the frozen total
+        assert_eq!(board.pay_out(0).unwrap(), 3_000);
+        assert_eq!(board.prize_pool, 5_000);
+
+        assert!(board.pay_out(0).is_err());
+        assert!(board.pay_out(2).is_err());
+        assert_eq!(board.prize_pool, 5_000);
+    }
+}
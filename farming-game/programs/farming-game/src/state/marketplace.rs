@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::BPS_DENOMINATOR;
+use crate::errors::FarmingError;
+use crate::state::inventory::{Inventory, ItemId};
+use crate::state::resources::ResourceType;
+
+/// Global order book for raw resources
+#[account]
+pub struct Marketplace {
+    pub authority: Pubkey,
+    pub next_listing_id: u64,        // Incremented for every new listing
+    pub fees_collected: u64,         // Coins owed to the GameConfig treasury
+    pub volume_traded: [u64; 4],     // Coins traded per resource [wood, stone, fiber, seeds]
+    pub bump: u8,
+}
+
+impl Marketplace {
+    pub const SPACE: usize = 8 // discriminator
+        + 32 // authority
+        + 8 // next_listing_id
+        + 8 // fees_collected
+        + 32 // volume_traded (4 × 8)
+        + 1; // bump
+}
+
+/// A sell order for one resource. The listed quantity is escrowed here
+/// (already removed from the seller's PlayerAccount).
+#[account]
+pub struct Listing {
+    pub seller: Pubkey,
+    pub listing_id: u64,
+    pub resource_type: u8,
+    pub price_per_unit: u64,         // Coins per unit
+    pub quantity: u16,               // Units still for sale
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Listing {
+    pub const SPACE: usize = 8 // discriminator
+        + 32 // seller
+        + 8 // listing_id
+        + 1 // resource_type
+        + 8 // price_per_unit
+        + 2 // quantity
+        + 8 // created_at
+        + 1; // bump
+
+    /// Total price of `quantity` units and the marketplace fee taken from it
+    pub fn quote(&self, quantity: u16, fee_bps: u16) -> Result<(u64, u64)> {
+        let total_price = self
+            .price_per_unit
+            .checked_mul(quantity as u64)
+            .ok_or(FarmingError::MathOverflow)?;
+        let fee = total_price
+            .checked_mul(fee_bps as u64)
+            .ok_or(FarmingError::MathOverflow)?
+            / BPS_DENOMINATOR;
+        Ok((total_price, fee))
+    }
+
+    /// Return unsold units to the seller's inventory as far as stack limits allow.
+    /// Whatever does not fit stays listed; returns the number of units handed back.
+    pub fn withdraw_capped(&mut self, inventory: &mut Inventory) -> Result<u16> {
+        let item = ItemId::from_resource(ResourceType::from_u8(self.resource_type)?);
+        let returned = inventory.add_capped(item, self.quantity);
+        self.quantity -= returned;
+        Ok(returned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn listing(price_per_unit: u64) -> Listing {
+        Listing {
+            seller: Pubkey::new_unique(),
+            listing_id: 0,
+            resource_type: 0,
+            price_per_unit,
+            quantity: 10,
+            created_at: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn fee_is_taken_from_the_total_and_rounds_down() {
+        assert_eq!(listing(10).quote(4, 250).unwrap(), (40, 1));
+        assert_eq!(listing(100).quote(10, 250).unwrap(), (1000, 25));
+        assert_eq!(listing(7).quote(3, 0).unwrap(), (21, 0));
+    }
+
+    #[test]
+    fn withdrawal_stops_at_the_stack_limit() {
+        let mut inventory = Inventory::default();
+        let limit = ItemId::Wood.max_stack_size();
+        inventory.add(ItemId::Wood, limit - 4).unwrap();
+
+        let mut listing = listing(10);
+        assert_eq!(listing.withdraw_capped(&mut inventory).unwrap(), 4);
+        assert_eq!(listing.quantity, 6);
+        assert_eq!(inventory.amount(ItemId::Wood), limit);
+
+        inventory.remove(ItemId::Wood, 10).unwrap();
+        assert_eq!(listing.withdraw_capped(&mut inventory).unwrap(), 6);
+        assert_eq!(listing.quantity, 0);
+    }
+
+    #[test]
+    fn overflowing_prices_are_rejected() {
+        assert!(listing(u64::MAX).quote(2, 0).is_err());
+        assert!(listing(u64::MAX / 2).quote(1, 250).is_err());
+    }
+}
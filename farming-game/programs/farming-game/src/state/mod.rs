@@ -1,19 +1,47 @@
 pub mod player;
+pub mod field;
 pub mod game_config;
 pub mod crop;
+pub mod soil;
+pub mod rotation;
 pub mod season;
+pub mod leaderboard;
 pub mod tools;
 pub mod resources;
+pub mod inventory;
+pub mod resource_node;
 pub mod recipes;
+pub mod recipe_registry;
+pub mod compost;
 pub mod synergy;
 pub mod pattern_detector;
+pub mod progression;
+pub mod achievements;
+pub mod quests;
+pub mod crafted_asset;
+pub mod trade;
+pub mod marketplace;
 
 pub use player::*;
+pub use field::*;
 pub use game_config::*;
 pub use crop::*;
+pub use soil::*;
+pub use rotation::*;
 pub use season::*;
+pub use leaderboard::*;
 pub use tools::*;
 pub use resources::*;
+pub use inventory::*;
+pub use resource_node::*;
 pub use recipes::*;
+pub use recipe_registry::*;
+pub use compost::*;
 pub use synergy::*;
 pub use pattern_detector::*;
+pub use progression::*;
+pub use achievements::*;
+pub use quests::*;
+pub use crafted_asset::*;
+pub use trade::*;
+pub use marketplace::*;
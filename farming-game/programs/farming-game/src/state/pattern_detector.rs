@@ -1,58 +1,96 @@
+use crate::constants::MAX_GRID_SIZE;
 use crate::state::crop::CropType;
+use crate::state::synergy::CompanionEffect;
 use crate::state::player::FarmTile;
-use super::synergy::PatternType;
+use super::synergy::{PatternMatch, PatternType};
 
-/// Pattern detector for the 5x5 farm grid
+/// A single pattern check: returns the tile bitmask of the match, if any
+type PatternCheck = fn(&[FarmTile], usize, usize, usize, i64) -> Option<u64>;
+
+/// Pattern detector for a square farm grid of any size (5x5 by default).
+/// Plots are stored row-major, so (row, col) maps to `row * grid_size + col`.
 pub struct PatternDetector;
 
 impl PatternDetector {
     /// Detect all patterns at a given position on the grid
-    /// Returns a vector of detected patterns
+    /// Returns each detected pattern together with the tiles that formed it
     pub fn detect_patterns(
-        plots: &[FarmTile; 25],
+        plots: &[FarmTile],
+        grid_size: usize,
         harvest_row: usize,
         harvest_col: usize,
         current_time: i64,
-    ) -> Vec<PatternType> {
+    ) -> Vec<PatternMatch> {
         let mut detected = Vec::new();
+        let anchor = (harvest_row * grid_size + harvest_col) as u8;
+        let checks: [(PatternType, PatternCheck); 12] = [
+            (PatternType::MonocultureRow, Self::check_monoculture_row),
+            (PatternType::MonocultureBlock, Self::check_monoculture_block),
+            (PatternType::CropDiversity, Self::check_crop_diversity),
+            (PatternType::CrossPattern, Self::check_cross_pattern),
+            (PatternType::Checkerboard, Self::check_checkerboard),
+            (PatternType::PerimeterDefense, Self::check_perimeter_defense),
+            (PatternType::RotationSequence, Self::check_rotation_sequence),
+            (PatternType::DiagonalLine, Self::check_diagonal_line),
+            (PatternType::FullBlock, Self::check_full_block),
+            (PatternType::Ring, Self::check_ring),
+            (PatternType::FullLine, Self::check_full_line),
+            (PatternType::MirrorSymmetry, Self::check_mirror_symmetry),
+        ];
 
         // Check each pattern type at this position
-        if Self::check_monoculture_row(plots, harvest_row, harvest_col, current_time) {
-            detected.push(PatternType::MonocultureRow);
-        }
-        if Self::check_monoculture_block(plots, harvest_row, harvest_col, current_time) {
-            detected.push(PatternType::MonocultureBlock);
-        }
-        if Self::check_crop_diversity(plots, harvest_row, harvest_col, current_time) {
-            detected.push(PatternType::CropDiversity);
-        }
-        if Self::check_cross_pattern(plots, harvest_row, harvest_col, current_time) {
-            detected.push(PatternType::CrossPattern);
-        }
-        if Self::check_checkerboard(plots, harvest_row, harvest_col, current_time) {
-            detected.push(PatternType::Checkerboard);
-        }
-        if Self::check_perimeter_defense(plots, harvest_row, harvest_col, current_time) {
-            detected.push(PatternType::PerimeterDefense);
-        }
-        if Self::check_rotation_sequence(plots, harvest_row, harvest_col, current_time) {
-            detected.push(PatternType::RotationSequence);
+        for (pattern, check) in checks.iter() {
+            if let Some(tiles) = check(plots, grid_size, harvest_row, harvest_col, current_time) {
+                detected.push(PatternMatch { pattern: *pattern, anchor, tiles });
+            }
         }
 
         detected
     }
 
+    /// Scan every tile of the grid and return each distinct pattern match once.
+    /// A pattern formed by the same tiles is reported only for its lowest anchor.
+    /// Grids never grow past MAX_GRID_SIZE, so one call checks at most 64 anchors.
+    pub fn detect_all_patterns(
+        plots: &[FarmTile],
+        grid_size: usize,
+        current_time: i64,
+    ) -> Vec<PatternMatch> {
+        let mut all: Vec<PatternMatch> = Vec::new();
+        let scan_size = grid_size.min(MAX_GRID_SIZE as usize);
+
+        for row in 0..scan_size {
+            for col in 0..scan_size {
+                for found in Self::detect_patterns(plots, grid_size, row, col, current_time) {
+                    if !all.iter().any(|m| m.pattern == found.pattern && m.tiles == found.tiles) {
+                        all.push(found);
+                    }
+                }
+            }
+        }
+
+        all
+    }
+
+    /// Build a tile bitmask (bit = row * grid_size + col) from grid coordinates
+    fn tile_mask(grid_size: usize, cells: &[(usize, usize)]) -> u64 {
+        cells
+            .iter()
+            .fold(0u64, |mask, (r, c)| mask | (1u64 << (r * grid_size + c)))
+    }
+
     /// Get the crop type at a specific grid position (returns None if out of bounds, empty, or not mature)
     fn get_crop_at(
-        plots: &[FarmTile; 25],
+        plots: &[FarmTile],
+        grid_size: usize,
         row: usize,
         col: usize,
         current_time: i64,
     ) -> Option<u8> {
-        if row >= 5 || col >= 5 {
+        if row >= grid_size || col >= grid_size {
             return None;
         }
-        let idx = row * 5 + col;
+        let idx = row * grid_size + col;
         let tile = &plots[idx];
         if tile.crop_type == 0 {
             return None;
@@ -71,74 +109,80 @@ impl PatternDetector {
     /// Check for 3+ same crops in a horizontal or vertical line
     /// The crop at (harvest_row, harvest_col) is part of the line
     fn check_monoculture_row(
-        plots: &[FarmTile; 25],
+        plots: &[FarmTile],
+        grid_size: usize,
         row: usize,
         col: usize,
         current_time: i64,
-    ) -> bool {
-        let Some(crop_type) = Self::get_crop_at(plots, row, col, current_time) else {
-            return false;
-        };
+    ) -> Option<u64> {
+        let crop_type = Self::get_crop_at(plots, grid_size, row, col, current_time)?;
 
         // Check horizontal (left and right)
-        let mut h_count = 1; // Current crop
+        let mut left = 0;
         
         // Count left
         for i in 1..=col {
-            if Self::get_crop_at(plots, row, col - i, current_time) == Some(crop_type) {
-                h_count += 1;
+            if Self::get_crop_at(plots, grid_size, row, col - i, current_time) == Some(crop_type) {
+                left += 1;
             } else {
                 break;
             }
         }
         
         // Count right
-        for i in 1..(5 - col) {
-            if Self::get_crop_at(plots, row, col + i, current_time) == Some(crop_type) {
-                h_count += 1;
+        let mut right = 0;
+        for i in 1..(grid_size - col) {
+            if Self::get_crop_at(plots, grid_size, row, col + i, current_time) == Some(crop_type) {
+                right += 1;
             } else {
                 break;
             }
         }
 
-        if h_count >= 3 {
-            return true;
+        if left + right + 1 >= 3 {
+            let cells: Vec<(usize, usize)> = (col - left..=col + right).map(|c| (row, c)).collect();
+            return Some(Self::tile_mask(grid_size, &cells));
         }
 
         // Check vertical (up and down)
-        let mut v_count = 1;
+        let mut up = 0;
         
         // Count up
         for i in 1..=row {
-            if Self::get_crop_at(plots, row - i, col, current_time) == Some(crop_type) {
-                v_count += 1;
+            if Self::get_crop_at(plots, grid_size, row - i, col, current_time) == Some(crop_type) {
+                up += 1;
             } else {
                 break;
             }
         }
         
         // Count down
-        for i in 1..(5 - row) {
-            if Self::get_crop_at(plots, row + i, col, current_time) == Some(crop_type) {
-                v_count += 1;
+        let mut down = 0;
+        for i in 1..(grid_size - row) {
+            if Self::get_crop_at(plots, grid_size, row + i, col, current_time) == Some(crop_type) {
+                down += 1;
             } else {
                 break;
             }
         }
 
-        v_count >= 3
+        if up + down + 1 >= 3 {
+            let cells: Vec<(usize, usize)> = (row - up..=row + down).map(|r| (r, col)).collect();
+            return Some(Self::tile_mask(grid_size, &cells));
+        }
+
+        None
     }
 
     /// Check for 2x2 block of same crop where this position is part of the block
     fn check_monoculture_block(
-        plots: &[FarmTile; 25],
+        plots: &[FarmTile],
+        grid_size: usize,
         row: usize,
         col: usize,
         current_time: i64,
-    ) -> bool {
-        let Some(crop_type) = Self::get_crop_at(plots, row, col, current_time) else {
-            return false;
-        };
+    ) -> Option<u64> {
+        let crop_type = Self::get_crop_at(plots, grid_size, row, col, current_time)?;
 
         // Check all 4 possible 2x2 configurations where this position is part
         let positions = [
@@ -155,26 +199,25 @@ impl PatternDetector {
         for config in positions.iter() {
             if config
                 .iter()
-                .all(|(r, c)| Self::get_crop_at(plots, *r, *c, current_time) == Some(crop_type))
+                .all(|(r, c)| Self::get_crop_at(plots, grid_size, *r, *c, current_time) == Some(crop_type))
             {
-                return true;
+                return Some(Self::tile_mask(grid_size, config));
             }
         }
 
-        false
+        None
     }
 
     /// Check if surrounded by all different crops (biodiversity bonus)
     /// Requires all 4 cardinal neighbors to be different from center and each other
     fn check_crop_diversity(
-        plots: &[FarmTile; 25],
+        plots: &[FarmTile],
+        grid_size: usize,
         row: usize,
         col: usize,
         current_time: i64,
-    ) -> bool {
-        let Some(center_crop) = Self::get_crop_at(plots, row, col, current_time) else {
-            return false;
-        };
+    ) -> Option<u64> {
+        let center_crop = Self::get_crop_at(plots, grid_size, row, col, current_time)?;
 
         // Get all 4 adjacent neighbors (cardinal directions only, not diagonal)
         let neighbors = [
@@ -186,44 +229,43 @@ impl PatternDetector {
 
         let mut neighbor_crops = Vec::new();
         for (r, c) in neighbors.iter() {
-            if let Some(crop) = Self::get_crop_at(plots, *r, *c, current_time) {
+            if let Some(crop) = Self::get_crop_at(plots, grid_size, *r, *c, current_time) {
                 neighbor_crops.push(crop);
             }
         }
 
         // Need exactly 4 neighbors (don't accept edge cases)
         if neighbor_crops.len() != 4 {
-            return false;
+            return None;
         }
 
         // All neighbors must be different from center crop
-        if neighbor_crops.iter().any(|c| *c == center_crop) {
-            return false;
+        if neighbor_crops.contains(&center_crop) {
+            return None;
         }
 
         // All neighbors must be different from each other (all different types)
         for i in 0..neighbor_crops.len() {
             for j in (i + 1)..neighbor_crops.len() {
                 if neighbor_crops[i] == neighbor_crops[j] {
-                    return false;
+                    return None;
                 }
             }
         }
 
-        true
+        Some(Self::tile_mask(grid_size, &neighbors) | Self::tile_mask(grid_size, &[(row, col)]))
     }
 
     /// Check for cross pattern (+ shape) of same crop
     /// Center crop with matching crops in all 4 cardinal directions
     fn check_cross_pattern(
-        plots: &[FarmTile; 25],
+        plots: &[FarmTile],
+        grid_size: usize,
         row: usize,
         col: usize,
         current_time: i64,
-    ) -> bool {
-        let Some(crop_type) = Self::get_crop_at(plots, row, col, current_time) else {
-            return false;
-        };
+    ) -> Option<u64> {
+        let crop_type = Self::get_crop_at(plots, grid_size, row, col, current_time)?;
 
         // All 4 cardinal neighbors must exist and have the same crop type
         let cross_positions = [
@@ -233,19 +275,24 @@ impl PatternDetector {
             (row, col + 1),              // Right
         ];
 
-        cross_positions
+        let is_cross = cross_positions
             .iter()
-            .all(|(r, c)| Self::get_crop_at(plots, *r, *c, current_time) == Some(crop_type))
+            .all(|(r, c)| Self::get_crop_at(plots, grid_size, *r, *c, current_time) == Some(crop_type));
+
+        is_cross.then(|| {
+            Self::tile_mask(grid_size, &cross_positions) | Self::tile_mask(grid_size, &[(row, col)])
+        })
     }
 
     /// Check for checkerboard pattern (alternating crops in 3x3 area)
     /// The position can be any corner of the 3x3
     fn check_checkerboard(
-        plots: &[FarmTile; 25],
+        plots: &[FarmTile],
+        grid_size: usize,
         row: usize,
         col: usize,
         current_time: i64,
-    ) -> bool {
+    ) -> Option<u64> {
         // Try all possible 3x3 positions where this cell is part of it
         let possible_starts = [
             (row, col),                 // Top-left
@@ -261,8 +308,8 @@ impl PatternDetector {
 
         for (start_row, start_col) in possible_starts.iter() {
             // Skip if this would go out of bounds
-            if *start_row >= 5 || *start_col >= 5 || 
-               start_row + 2 >= 5 || start_col + 2 >= 5 {
+            if *start_row >= grid_size || *start_col >= grid_size ||
+               start_row + 2 >= grid_size || start_col + 2 >= grid_size {
                 continue;
             }
 
@@ -277,7 +324,7 @@ impl PatternDetector {
                     let cell_col = start_col + j;
                     let is_even = (i + j) % 2 == 0;
 
-                    if let Some(crop) = Self::get_crop_at(plots, cell_row, cell_col, current_time) {
+                    if let Some(crop) = Self::get_crop_at(plots, grid_size, cell_row, cell_col, current_time) {
                         if is_even {
                             if let Some(first) = pattern_crop_a {
                                 if crop != first {
@@ -287,15 +334,13 @@ impl PatternDetector {
                             } else {
                                 pattern_crop_a = Some(crop);
                             }
-                        } else {
-                            if let Some(first) = pattern_crop_b {
-                                if crop != first {
-                                    is_checkerboard = false;
-                                    break;
-                                }
-                            } else {
-                                pattern_crop_b = Some(crop);
+                        } else if let Some(first) = pattern_crop_b {
+                            if crop != first {
+                                is_checkerboard = false;
+                                break;
                             }
+                        } else {
+                            pattern_crop_b = Some(crop);
                         }
                     } else {
                         is_checkerboard = false;
@@ -308,31 +353,32 @@ impl PatternDetector {
             }
 
             // Must have two different crop types in the pattern
-            if is_checkerboard && pattern_crop_a.is_some() && pattern_crop_b.is_some() {
-                if pattern_crop_a != pattern_crop_b {
-                    return true;
-                }
+            if is_checkerboard
+                && pattern_crop_a.is_some()
+                && pattern_crop_b.is_some()
+                && pattern_crop_a != pattern_crop_b
+            {
+                return Some(Self::square_mask(grid_size, *start_row, *start_col, 3));
             }
         }
 
-        false
+        None
     }
 
     /// Check for perimeter defense pattern (border of different crops around center)
     /// 3x3 area where center is surrounded by 8 different crops
     fn check_perimeter_defense(
-        plots: &[FarmTile; 25],
+        plots: &[FarmTile],
+        grid_size: usize,
         row: usize,
         col: usize,
         current_time: i64,
-    ) -> bool {
-        let Some(center_crop) = Self::get_crop_at(plots, row, col, current_time) else {
-            return false;
-        };
+    ) -> Option<u64> {
+        let center_crop = Self::get_crop_at(plots, grid_size, row, col, current_time)?;
 
-        // Need at least (1,1) to (3,3) to have a 3x3 with center
-        if row < 1 || row > 3 || col < 1 || col > 3 {
-            return false;
+        // Center must not sit on the grid edge to have a full 3x3 around it
+        if !(1..grid_size - 1).contains(&row) || !(1..grid_size - 1).contains(&col) {
+            return None;
         }
 
         // Get all 8 surrounding cells
@@ -346,43 +392,47 @@ impl PatternDetector {
         // 1. Have crops
         // 2. Be different from center crop
         // 3. Not need to be different from each other (just defending the center)
-        perimeter.iter().all(|(r, c)| {
-            if let Some(crop) = Self::get_crop_at(plots, *r, *c, current_time) {
+        let defended = perimeter.iter().all(|(r, c)| {
+            if let Some(crop) = Self::get_crop_at(plots, grid_size, *r, *c, current_time) {
                 crop != center_crop
             } else {
                 false
             }
-        })
+        });
+
+        defended.then(|| Self::square_mask(grid_size, row - 1, col - 1, 3))
+    }
+
+    /// Bitmask of a `size`×`size` square whose top-left corner is (start_row, start_col)
+    fn square_mask(grid_size: usize, start_row: usize, start_col: usize, size: usize) -> u64 {
+        let cells: Vec<(usize, usize)> = (start_row..start_row + size)
+            .flat_map(|r| (start_col..start_col + size).map(move |c| (r, c)))
+            .collect();
+        Self::tile_mask(grid_size, &cells)
     }
 
     /// Check for rotation sequence (4 different crops in a line)
     /// Horizontal or vertical line with all 4 different crop types
     fn check_rotation_sequence(
-        plots: &[FarmTile; 25],
+        plots: &[FarmTile],
+        grid_size: usize,
         row: usize,
         col: usize,
         current_time: i64,
-    ) -> bool {
-        // Check horizontal sequences containing this position
-        if Self::check_rotation_sequence_horizontal(plots, row, col, current_time) {
-            return true;
-        }
-
-        // Check vertical sequences containing this position
-        if Self::check_rotation_sequence_vertical(plots, row, col, current_time) {
-            return true;
-        }
-
-        false
+    ) -> Option<u64> {
+        // Check horizontal sequences containing this position, then vertical ones
+        Self::check_rotation_sequence_horizontal(plots, grid_size, row, col, current_time)
+            .or_else(|| Self::check_rotation_sequence_vertical(plots, grid_size, row, col, current_time))
     }
 
     /// Helper: check horizontal rotation sequence
     fn check_rotation_sequence_horizontal(
-        plots: &[FarmTile; 25],
+        plots: &[FarmTile],
+        grid_size: usize,
         row: usize,
         col: usize,
         current_time: i64,
-    ) -> bool {
+    ) -> Option<u64> {
         // Try all 4-crop windows containing this column
         let start_positions = [
             (col.wrapping_sub(3), col),
@@ -392,13 +442,14 @@ impl PatternDetector {
         ];
 
         for (start_col, end_col) in start_positions.iter() {
-            if *start_col >= 5 || *end_col >= 5 {
+            if *start_col >= grid_size || *end_col >= grid_size {
                 continue;
             }
 
+            let window: Vec<(usize, usize)> = (*start_col..=*end_col).map(|c| (row, c)).collect();
             let mut crops = Vec::new();
             for c in *start_col..=*end_col {
-                if let Some(crop) = Self::get_crop_at(plots, row, c, current_time) {
+                if let Some(crop) = Self::get_crop_at(plots, grid_size, row, c, current_time) {
                     crops.push(crop);
                 } else {
                     break;
@@ -420,21 +471,22 @@ impl PatternDetector {
                     }
                 }
                 if unique {
-                    return true;
+                    return Some(Self::tile_mask(grid_size, &window));
                 }
             }
         }
 
-        false
+        None
     }
 
     /// Helper: check vertical rotation sequence
     fn check_rotation_sequence_vertical(
-        plots: &[FarmTile; 25],
+        plots: &[FarmTile],
+        grid_size: usize,
         row: usize,
         col: usize,
         current_time: i64,
-    ) -> bool {
+    ) -> Option<u64> {
         // Try all 4-crop windows containing this row
         let start_positions = [
             (row.wrapping_sub(3), row),
@@ -444,13 +496,14 @@ impl PatternDetector {
         ];
 
         for (start_row, end_row) in start_positions.iter() {
-            if *start_row >= 5 || *end_row >= 5 {
+            if *start_row >= grid_size || *end_row >= grid_size {
                 continue;
             }
 
+            let window: Vec<(usize, usize)> = (*start_row..=*end_row).map(|r| (r, col)).collect();
             let mut crops = Vec::new();
             for r in *start_row..=*end_row {
-                if let Some(crop) = Self::get_crop_at(plots, r, col, current_time) {
+                if let Some(crop) = Self::get_crop_at(plots, grid_size, r, col, current_time) {
                     crops.push(crop);
                 } else {
                     break;
@@ -472,25 +525,190 @@ impl PatternDetector {
                     }
                 }
                 if unique {
-                    return true;
+                    return Some(Self::tile_mask(grid_size, &window));
                 }
             }
         }
 
-        false
+        None
     }
 
-    /// Check companion planting (specific beneficial crop pairs adjacent)
-    /// Returns the companion crop type if a beneficial pairing is detected
-    pub fn check_companion_planting(
-        plots: &[FarmTile; 25],
+    /// Check for 3+ same crops along either diagonal through this position
+    fn check_diagonal_line(
+        plots: &[FarmTile],
+        grid_size: usize,
         row: usize,
         col: usize,
         current_time: i64,
-    ) -> Option<u8> {
-        let Some(crop_type) = Self::get_crop_at(plots, row, col, current_time) else {
+    ) -> Option<u64> {
+        let crop_type = Self::get_crop_at(plots, grid_size, row, col, current_time)?;
+
+        // (row step, col step) of the "back" direction; the line also extends the opposite way
+        for (dr, dc) in [(-1isize, -1isize), (-1, 1)] {
+            let mut cells = vec![(row, col)];
+
+            for sign in [1isize, -1] {
+                let mut r = row as isize;
+                let mut c = col as isize;
+                loop {
+                    r += dr * sign;
+                    c += dc * sign;
+                    if r < 0 || c < 0 {
+                        break;
+                    }
+                    if Self::get_crop_at(plots, grid_size, r as usize, c as usize, current_time) != Some(crop_type) {
+                        break;
+                    }
+                    cells.push((r as usize, c as usize));
+                }
+            }
+
+            if cells.len() >= 3 {
+                return Some(Self::tile_mask(grid_size, &cells));
+            }
+        }
+
+        None
+    }
+
+    /// Check for a full 3x3 block of the same crop containing this position
+    fn check_full_block(
+        plots: &[FarmTile],
+        grid_size: usize,
+        row: usize,
+        col: usize,
+        current_time: i64,
+    ) -> Option<u64> {
+        let crop_type = Self::get_crop_at(plots, grid_size, row, col, current_time)?;
+
+        for start_row in row.saturating_sub(2)..=row {
+            for start_col in col.saturating_sub(2)..=col {
+                if start_row + 2 >= grid_size || start_col + 2 >= grid_size {
+                    continue;
+                }
+
+                let full = (0..3).all(|i| {
+                    (0..3).all(|j| {
+                        Self::get_crop_at(plots, grid_size, start_row + i, start_col + j, current_time) == Some(crop_type)
+                    })
+                });
+                if full {
+                    return Some(Self::square_mask(grid_size, start_row, start_col, 3));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Check for a hollow 3x3 ring: 8 same crops around a center that is empty or different
+    /// This position must be one of the ring tiles
+    fn check_ring(
+        plots: &[FarmTile],
+        grid_size: usize,
+        row: usize,
+        col: usize,
+        current_time: i64,
+    ) -> Option<u64> {
+        let crop_type = Self::get_crop_at(plots, grid_size, row, col, current_time)?;
+
+        // Try every interior center whose ring passes through this position
+        for center_row in row.saturating_sub(1)..=row + 1 {
+            for center_col in col.saturating_sub(1)..=col + 1 {
+                if (center_row, center_col) == (row, col) {
+                    continue;
+                }
+                if !(1..grid_size - 1).contains(&center_row) || !(1..grid_size - 1).contains(&center_col) {
+                    continue;
+                }
+                if Self::get_crop_at(plots, grid_size, center_row, center_col, current_time) == Some(crop_type) {
+                    continue;
+                }
+
+                let ring = [
+                    (center_row - 1, center_col - 1), (center_row - 1, center_col), (center_row - 1, center_col + 1),
+                    (center_row, center_col - 1),                                   (center_row, center_col + 1),
+                    (center_row + 1, center_col - 1), (center_row + 1, center_col), (center_row + 1, center_col + 1),
+                ];
+                if ring
+                    .iter()
+                    .all(|(r, c)| Self::get_crop_at(plots, grid_size, *r, *c, current_time) == Some(crop_type))
+                {
+                    return Some(Self::tile_mask(grid_size, &ring));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Check whether this position's entire row or column holds the same crop
+    fn check_full_line(
+        plots: &[FarmTile],
+        grid_size: usize,
+        row: usize,
+        col: usize,
+        current_time: i64,
+    ) -> Option<u64> {
+        let crop_type = Self::get_crop_at(plots, grid_size, row, col, current_time)?;
+
+        if (0..grid_size).all(|c| Self::get_crop_at(plots, grid_size, row, c, current_time) == Some(crop_type)) {
+            let cells: Vec<(usize, usize)> = (0..grid_size).map(|c| (row, c)).collect();
+            return Some(Self::tile_mask(grid_size, &cells));
+        }
+
+        if (0..grid_size).all(|r| Self::get_crop_at(plots, grid_size, r, col, current_time) == Some(crop_type)) {
+            let cells: Vec<(usize, usize)> = (0..grid_size).map(|r| (r, col)).collect();
+            return Some(Self::tile_mask(grid_size, &cells));
+        }
+
+        None
+    }
+
+    /// Check whole-farm mirror symmetry (left-right or top-bottom)
+    /// Every tile must hold a mature crop, and more than one crop type must be used
+    fn check_mirror_symmetry(
+        plots: &[FarmTile],
+        grid_size: usize,
+        row: usize,
+        col: usize,
+        current_time: i64,
+    ) -> Option<u64> {
+        Self::get_crop_at(plots, grid_size, row, col, current_time)?;
+
+        let mut crops = Vec::with_capacity(grid_size * grid_size);
+        for r in 0..grid_size {
+            for c in 0..grid_size {
+                crops.push(Self::get_crop_at(plots, grid_size, r, c, current_time)?);
+            }
+        }
+
+        // A single-crop farm is trivially symmetric; that is the monoculture patterns' job
+        if crops.iter().all(|crop| *crop == crops[0]) {
             return None;
-        };
+        }
+
+        let at = |r: usize, c: usize| crops[r * grid_size + c];
+        let left_right = (0..grid_size).all(|r| (0..grid_size).all(|c| at(r, c) == at(r, grid_size - 1 - c)));
+        let top_bottom = (0..grid_size).all(|r| (0..grid_size).all(|c| at(r, c) == at(grid_size - 1 - r, c)));
+
+        if left_right || top_bottom {
+            Some(Self::square_mask(grid_size, 0, 0, grid_size))
+        } else {
+            None
+        }
+    }
+
+    /// Check companion planting against all four cardinal neighbours
+    /// Returns the stacked (and capped) companion effect, or None if the tile holds no crop
+    pub fn check_companion_planting(
+        plots: &[FarmTile],
+        grid_size: usize,
+        row: usize,
+        col: usize,
+        current_time: i64,
+    ) -> Option<CompanionEffect> {
+        let crop_type = CropType::from_u8(Self::get_crop_at(plots, grid_size, row, col, current_time)?)?;
 
         let neighbors = [
             (row.wrapping_sub(1), col),
@@ -499,31 +717,233 @@ impl PatternDetector {
             (row, col + 1),
         ];
 
-        for (r, c) in neighbors.iter() {
-            if let Some(neighbor_crop) = Self::get_crop_at(plots, *r, *c, current_time) {
-                // Check if this pair has a companion bonus
-                if let (Some(center), Some(neighbor)) = (
-                    Self::crop_type_from_u8(crop_type),
-                    Self::crop_type_from_u8(neighbor_crop),
-                ) {
-                    if super::synergy::get_companion_bonus(center, neighbor).is_some() {
-                        return Some(neighbor_crop);
-                    }
-                }
+        let neighbor_crops: Vec<CropType> = neighbors
+            .iter()
+            .filter_map(|(r, c)| Self::get_crop_at(plots, grid_size, *r, *c, current_time))
+            .filter_map(CropType::from_u8)
+            .collect();
+
+        Some(CompanionEffect::from_neighbors(crop_type, &neighbor_crops))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_000_000;
+    const W: u8 = 1; // Wheat
+    const T: u8 = 2; // Tomato
+    const C: u8 = 3; // Corn
+    const R: u8 = 4; // Carrot
+    const L: u8 = 5; // Lettuce
+
+    /// Build a mature 5x5 grid from crop ids (0 = empty)
+    fn grid(crops: [u8; 25]) -> [FarmTile; 25] {
+        let mut tiles = [FarmTile::default(); 25];
+        for (tile, crop) in tiles.iter_mut().zip(crops.iter()) {
+            tile.crop_type = *crop;
+        }
+        tiles
+    }
+
+    fn find(tiles: &[FarmTile; 25], idx: usize, pattern: PatternType) -> Option<PatternMatch> {
+        PatternDetector::detect_patterns(tiles, 5, idx / 5, idx % 5, NOW)
+            .into_iter()
+            .find(|m| m.pattern == pattern)
+    }
+
+    fn mask(indices: &[usize]) -> u64 {
+        indices.iter().fold(0, |m, i| m | (1u64 << i))
+    }
+
+    #[test]
+    fn diagonal_line_both_directions() {
+        let tiles = grid([
+            W, 0, 0, 0, C,
+            0, W, 0, C, 0,
+            0, 0, C, 0, 0,
+            0, 0, 0, W, 0,
+            0, 0, 0, 0, 0,
+        ]);
+        // Anti-diagonal of corn through the center
+        for idx in [4, 8, 12] {
+            let found = find(&tiles, idx, PatternType::DiagonalLine).unwrap();
+            assert_eq!(found.tiles, mask(&[4, 8, 12]));
+        }
+        // Two wheat are not enough, and the main diagonal is broken by corn
+        for idx in [0, 6, 18] {
+            assert!(find(&tiles, idx, PatternType::DiagonalLine).is_none());
+        }
+
+        let tiles = grid([
+            R, 0, 0, 0, 0,
+            0, R, 0, 0, 0,
+            0, 0, R, 0, 0,
+            0, 0, 0, R, 0,
+            0, 0, 0, 0, R,
+        ]);
+        for idx in [0, 6, 12, 18, 24] {
+            let found = find(&tiles, idx, PatternType::DiagonalLine).unwrap();
+            assert_eq!(found.tiles, mask(&[0, 6, 12, 18, 24]));
+        }
+    }
+
+    #[test]
+    fn full_block_every_position() {
+        let tiles = grid([
+            L, L, L, 0, 0,
+            L, L, L, 0, 0,
+            L, L, L, 0, 0,
+            0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0,
+        ]);
+        let block = mask(&[0, 1, 2, 5, 6, 7, 10, 11, 12]);
+        for idx in 0..25 {
+            let found = find(&tiles, idx, PatternType::FullBlock);
+            if block & (1 << idx) != 0 {
+                assert_eq!(found.unwrap().tiles, block);
+            } else {
+                assert!(found.is_none());
             }
         }
 
-        None
+        // One different tile breaks the block
+        let mut broken = tiles;
+        broken[6].crop_type = W;
+        assert!(find(&broken, 0, PatternType::FullBlock).is_none());
+    }
+
+    #[test]
+    fn ring_requires_hollow_center() {
+        let mut crops = [0; 25];
+        for idx in [6, 7, 8, 11, 13, 16, 17, 18] {
+            crops[idx] = T;
+        }
+        let ring = mask(&[6, 7, 8, 11, 13, 16, 17, 18]);
+
+        // Empty center
+        let tiles = grid(crops);
+        for idx in [6, 7, 8, 11, 13, 16, 17, 18] {
+            assert_eq!(find(&tiles, idx, PatternType::Ring).unwrap().tiles, ring);
+        }
+        assert!(find(&tiles, 12, PatternType::Ring).is_none());
+
+        // Different crop in the center still counts
+        crops[12] = W;
+        assert!(find(&grid(crops), 7, PatternType::Ring).is_some());
+
+        // Same crop in the center is a full block, not a ring
+        crops[12] = T;
+        let tiles = grid(crops);
+        assert!(find(&tiles, 7, PatternType::Ring).is_none());
+        assert!(find(&tiles, 7, PatternType::FullBlock).is_some());
     }
 
-    fn crop_type_from_u8(value: u8) -> Option<CropType> {
-        match value {
-            1 => Some(CropType::Wheat),
-            2 => Some(CropType::Tomato),
-            3 => Some(CropType::Corn),
-            4 => Some(CropType::Carrot),
-            5 => Some(CropType::Lettuce),
-            _ => None,
+    #[test]
+    fn full_line_rows_and_columns() {
+        let tiles = grid([
+            C, C, C, C, C,
+            0, R, 0, 0, 0,
+            0, R, 0, 0, 0,
+            0, R, 0, 0, 0,
+            W, R, W, W, W,
+        ]);
+        for idx in 0..5 {
+            assert_eq!(find(&tiles, idx, PatternType::FullLine).unwrap().tiles, mask(&[0, 1, 2, 3, 4]));
         }
+        // Column 1 is corn on top, carrot below: not a full line
+        assert!(find(&tiles, 6, PatternType::FullLine).is_none());
+        // Row 4 is interrupted by a carrot
+        assert!(find(&tiles, 20, PatternType::FullLine).is_none());
+
+        let mut crops = [0; 25];
+        for r in 0..5 {
+            crops[r * 5 + 3] = L;
+        }
+        let tiles = grid(crops);
+        for r in 0..5 {
+            let found = find(&tiles, r * 5 + 3, PatternType::FullLine).unwrap();
+            assert_eq!(found.tiles, mask(&[3, 8, 13, 18, 23]));
+        }
+    }
+
+    #[test]
+    fn mirror_symmetry_whole_farm() {
+        let symmetric = [
+            W, T, C, T, W,
+            R, L, L, L, R,
+            C, C, W, C, C,
+            T, R, L, R, T,
+            L, W, C, W, L,
+        ];
+        let tiles = grid(symmetric);
+        for idx in 0..25 {
+            assert_eq!(find(&tiles, idx, PatternType::MirrorSymmetry).unwrap().tiles, u64::MAX >> 39);
+        }
+
+        // Top-bottom symmetry also counts
+        let vertical = [
+            W, T, C, R, L,
+            T, C, R, L, W,
+            C, R, L, W, T,
+            T, C, R, L, W,
+            W, T, C, R, L,
+        ];
+        assert!(find(&grid(vertical), 7, PatternType::MirrorSymmetry).is_some());
+
+        // One swapped tile breaks it
+        let mut broken = symmetric;
+        broken[0] = R;
+        assert!(find(&grid(broken), 12, PatternType::MirrorSymmetry).is_none());
+
+        // One empty tile breaks it
+        let mut gap = symmetric;
+        gap[24] = 0;
+        assert!(find(&grid(gap), 12, PatternType::MirrorSymmetry).is_none());
+
+        // A single-crop farm does not count
+        assert!(find(&grid([W; 25]), 12, PatternType::MirrorSymmetry).is_none());
+    }
+
+    #[test]
+    fn immature_crops_do_not_form_patterns() {
+        let mut tiles = grid([W; 25]);
+        for tile in tiles.iter_mut() {
+            tile.planted_at = NOW;
+        }
+        for idx in 0..25 {
+            assert!(PatternDetector::detect_patterns(&tiles, 5, idx / 5, idx % 5, NOW).is_empty());
+        }
+    }
+
+    #[test]
+    fn detect_all_patterns_deduplicates() {
+        let tiles = grid([
+            L, L, L, 0, 0,
+            L, L, L, 0, 0,
+            L, L, L, 0, 0,
+            0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0,
+        ]);
+        let all = PatternDetector::detect_all_patterns(&tiles, 5, NOW);
+        let blocks: Vec<_> = all.iter().filter(|m| m.pattern == PatternType::FullBlock).collect();
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].anchor, 0);
+    }
+
+    #[test]
+    fn detect_all_patterns_covers_the_largest_grid() {
+        // 8x8 grid with a lettuce block in the top-left and another in the bottom-right corner
+        let mut tiles = [FarmTile::default(); 64];
+        for (row, col) in [(0, 0), (0, 1), (1, 0), (1, 1), (6, 6), (6, 7), (7, 6), (7, 7)] {
+            tiles[row * 8 + col].crop_type = L;
+        }
+
+        let all = PatternDetector::detect_all_patterns(&tiles, 8, NOW);
+        let blocks: Vec<_> = all.iter().filter(|m| m.pattern == PatternType::MonocultureBlock).collect();
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].anchor, 0);
+        assert_eq!(blocks[1].anchor, 54);
     }
 }
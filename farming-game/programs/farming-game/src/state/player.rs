@@ -1,12 +1,31 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::{PLAYER_ACCOUNT_VERSION, TILE_COUNT};
+use crate::constants::{MAX_COMPOST_BINS, MAX_FERTILITY, MAX_RECIPES, MAX_SOIL_LEVEL, PLAYER_ACCOUNT_VERSION};
+use crate::errors::FarmingError;
+use crate::state::achievements::AchievementProgress;
+use crate::state::quests::QuestBoard;
+use crate::state::recipes::CraftableItem;
+use crate::state::compost::CompostBin;
+use crate::state::field::FarmGrid;
+use crate::state::inventory::{Inventory, ItemId};
+use crate::state::resources::ResourceType;
+use crate::state::rotation::RotationHistory;
+use crate::state::soil::{FertilizerState, SoilProfile};
+use crate::state::tools::{Tool, ToolKind, ToolTier};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
 pub struct CraftingJob {
     pub item_id: u8,
     pub started_at: i64,
     pub duration: i64,
+    pub output_quantity: u16,     // Taken from the recipe when the job was queued
+}
+
+impl CraftingJob {
+    pub const SPACE: usize = 1 // item_id
+        + 8 // started_at
+        + 8 // duration
+        + 2; // output_quantity
 }
 
 #[account]
@@ -14,35 +33,28 @@ pub struct PlayerAccount {
     // Existing fields (kept for compatibility)
     pub owner: Pubkey,
     pub coins: u64,
-    pub farm_tiles: [FarmTile; TILE_COUNT],
-
-    // Water management system
-    pub water_levels: [u8; TILE_COUNT],           // Soil moisture per plot (0-100%)
-    pub last_watered: [i64; TILE_COUNT],          // Timestamp of last watering per plot
-    pub last_water_decay_check: i64,              // Last timestamp water decay was applied
+    pub xp: u64,                                  // Total experience earned
+    pub level: u8,                                // Derived from xp (see progression::level_for_xp)
+    pub achievements: AchievementProgress,        // Unlocked badges and their counters
+    pub quests: QuestBoard,                       // Daily and weekly quests
+    pub season_earnings: u64,                     // Harvest coins earned in earnings_season/earnings_year
+    pub earnings_season: u8,
+    pub earnings_year: u32,
+    pub farm: FarmGrid,                           // Home farm (tiles, water, grid size)
+    pub fields_owned: u8,                         // Extra Field PDAs bought (ids 1..=fields_owned)
 
     // Tool inventory
-    pub watering_can_uses: u8,                    // Remaining uses before refill (max 10)
-    pub fertilizer_count: u16,                    // Number of fertilizers available
-    pub premium_seeds: u16,                       // Higher quality seeds (future use)
-
-    // Raw resources (gathered or from harvests)
-    pub wood: u16,                                // Collected from gathering
-    pub stone: u16,                               // Collected from gathering
-    pub fiber: u16,                               // From harvests and gathering
-    pub seeds: u16,                               // From harvests and gathering
-
-    // Crafted items (permanent structures)
-    pub compost_bin_count: u8,                    // Generates 1 fertilizer per day
-    pub scarecrow_count: u8,                      // Protects crops (future)
-    pub fence_count: u8,                          // Increases fertility cap (future)
-    pub sprinkler_count: u8,                      // Auto-waters plots (future)
-    pub advanced_tools: u8,                       // Waters 3x3 area (future)
+    pub watering_can_uses: u8,                    // Remaining uses before refill (can tier capacity)
+    pub tools: [Tool; ToolKind::COUNT],           // Tier and durability, indexed by ToolKind
+
+    // Item inventory: resources, intermediate goods, consumables and crafted structures
+    pub inventory: Inventory,
 
     // Crafting state
     pub crafting_queue: Option<CraftingJob>,      // Current crafting job (if any)
-    pub last_gather_time: [i64; 4],              // Cooldowns: [wood, stone, fiber, seeds]
-    pub last_compost_collection: i64,             // Last time fertilizer was collected from compost
+    pub crafts_completed: u16,                    // Crafts finished (instant or claimed)
+    pub unlocked_recipes: u16,                    // Bit per item id unlocked from the registry
+    pub compost_bins: [CompostBin; MAX_COMPOST_BINS], // Per-bin state (one per CompostBin item held)
 
     // Account versioning
     pub account_version: u8,
@@ -50,84 +62,63 @@ pub struct PlayerAccount {
 }
 
 impl PlayerAccount {
-    pub const SPACE: usize = 8 // discriminator
+    /// Account size with a home farm of `grid_size` x `grid_size` tiles
+    pub const fn space(grid_size: u8) -> usize {
+        Self::FIXED_SPACE + FarmGrid::space(grid_size)
+    }
+
+    /// Size of everything except the home farm grid
+    const FIXED_SPACE: usize = 8 // discriminator
         + 32 // owner
         + 8 // coins
-        + (TILE_COUNT * FarmTile::SPACE) // farm tiles
-        + TILE_COUNT // water_levels
-        + (TILE_COUNT * 8) // last_watered
-        + 8 // last_water_decay_check
+        + 8 // xp
+        + 1 // level
+        + AchievementProgress::SPACE // achievements
+        + QuestBoard::SPACE // quests
+        + 8 // season_earnings
+        + 1 // earnings_season
+        + 4 // earnings_year
+        + 1 // fields_owned
         + 1 // watering_can_uses
-        + 2 // fertilizer_count
-        + 2 // premium_seeds
-        + 2 // wood
-        + 2 // stone
-        + 2 // fiber
-        + 2 // seeds
-        + 1 // compost_bin_count
-        + 1 // scarecrow_count
-        + 1 // fence_count
-        + 1 // sprinkler_count
-        + 1 // advanced_tools
-        + 17 // crafting_queue (Option<CraftingJob> = 1 tag + 8 + 8)
-        + 32 // last_gather_time (4 × 8)
-        + 8 // last_compost_collection
+        + Tool::SPACE * ToolKind::COUNT // tools
+        + Inventory::SPACE // inventory
+        + 1 + CraftingJob::SPACE // crafting_queue (Option tag + job)
+        + 2 // crafts_completed
+        + 2 // unlocked_recipes
+        + CompostBin::SPACE * MAX_COMPOST_BINS // compost_bins
         + 1 // account_version
         + 1; // bump
 
-    pub fn init(&mut self, owner: Pubkey, bump: u8, tiles: [FarmTile; TILE_COUNT]) {
+    pub fn init(&mut self, owner: Pubkey, bump: u8, farm: FarmGrid) {
         self.owner = owner;
         self.coins = 0;
-        self.farm_tiles = tiles;
-        
-        // Initialize water levels to 70% and last_watered to 0
-        self.water_levels = [70u8; TILE_COUNT];
-        self.last_watered = [0i64; TILE_COUNT];
-        self.last_water_decay_check = 0;
+        self.xp = 0;
+        self.level = 1;
+        self.achievements = AchievementProgress::default();
+        self.quests = QuestBoard::default();
+        self.season_earnings = 0;
+        self.earnings_season = 0;
+        self.earnings_year = 0;
+        self.farm = farm;
+        self.fields_owned = 0;
         
         // Initialize tool inventory
-        self.watering_can_uses = 10;
-        self.fertilizer_count = 5;
-        self.premium_seeds = 0;
-        
-        // Initialize starting resources
-        self.wood = 10;
-        self.stone = 5;
-        self.fiber = 8;
-        self.seeds = 0;
+        self.tools = [Tool::new(ToolTier::Basic); ToolKind::COUNT];
+        self.refill_watering_can();
         
-        // Initialize crafted items (all at 0)
-        self.compost_bin_count = 0;
-        self.scarecrow_count = 0;
-        self.fence_count = 0;
-        self.sprinkler_count = 0;
-        self.advanced_tools = 0;
+        // Initialize starting items (crafted structures start at 0)
+        self.inventory = Inventory::starter();
         
         // Initialize crafting state
         self.crafting_queue = None;
-        self.last_gather_time = [0i64; 4];
-        self.last_compost_collection = 0;
+        self.crafts_completed = 0;
+        self.unlocked_recipes = 0;
+        self.compost_bins = [CompostBin::default(); MAX_COMPOST_BINS];
         
         self.account_version = PLAYER_ACCOUNT_VERSION;
         self.bump = bump;
     }
 
-    /// Apply water decay to all plots based on days elapsed
-    pub fn apply_water_decay(&mut self, current_timestamp: i64) {
-        let seconds_per_day = 86400i64;
-        let days_elapsed = current_timestamp.saturating_sub(self.last_water_decay_check) / seconds_per_day;
-        
-        if days_elapsed > 0 {
-            let decay_amount = (days_elapsed as u8).saturating_mul(5); // 5% per day
-            
-            for water_level in self.water_levels.iter_mut() {
-                *water_level = water_level.saturating_sub(decay_amount);
-            }
-            
-            self.last_water_decay_check = current_timestamp;
-        }
-    }
-
     /// Check if player is currently crafting
     pub fn is_crafting(&self) -> bool {
         self.crafting_queue.is_some()
@@ -142,52 +133,140 @@ impl PlayerAccount {
         }
     }
 
-    /// Consume resources from player inventory
+    /// Consume raw resources from player inventory. Nothing is taken unless all are held.
     pub fn consume_resources(&mut self, resource_costs: &[(u8, u16)]) -> Result<()> {
-        // First validate we have all resources
         for &(resource_type, amount) in resource_costs {
-            match resource_type {
-                0 => require!(self.wood >= amount, crate::errors::FarmingError::InsufficientResources),
-                1 => require!(self.stone >= amount, crate::errors::FarmingError::InsufficientResources),
-                2 => require!(self.fiber >= amount, crate::errors::FarmingError::InsufficientResources),
-                3 => require!(self.seeds >= amount, crate::errors::FarmingError::InsufficientResources),
-                _ => return Err(crate::errors::FarmingError::InvalidResourceType.into()),
-            }
+            let item = ItemId::from_resource(ResourceType::from_u8(resource_type)?);
+            require!(self.inventory.has(item, amount), item.shortage_error());
         }
-        
-        // Then consume them
-        for &(resource_type, amount) in resource_costs {
-            match resource_type {
-                0 => self.wood = self.wood.saturating_sub(amount),
-                1 => self.stone = self.stone.saturating_sub(amount),
-                2 => self.fiber = self.fiber.saturating_sub(amount),
-                3 => self.seeds = self.seeds.saturating_sub(amount),
-                _ => return Err(crate::errors::FarmingError::InvalidResourceType.into()),
-            }
+
+        self.inventory.remove_all(resource_costs)
+    }
+
+    /// Credit raw resources to player inventory, respecting each resource's max stack size.
+    /// Nothing is credited unless every resource fits.
+    pub fn add_resources(&mut self, resource_gains: &[(u8, u16)]) -> Result<()> {
+        for &(resource_type, amount) in resource_gains {
+            let item = ItemId::from_resource(ResourceType::from_u8(resource_type)?);
+            require!(
+                self.inventory.free_capacity(item) >= amount,
+                FarmingError::ResourceStackOverflow
+            );
         }
-        
-        Ok(())
+
+        self.inventory.add_all(resource_gains)
     }
 
-    /// Get plot at a specific (row, col) position on the 5x5 grid
-    pub fn get_plot(&self, row: usize, col: usize) -> Option<&FarmTile> {
-        if row >= 5 || col >= 5 {
-            return None;
+    /// Current amount held of a raw resource
+    pub fn resource_amount(&self, resource: ResourceType) -> u16 {
+        self.inventory.amount(ItemId::from_resource(resource))
+    }
+
+    /// Add harvest coins to the running total for `season`/`year`, restarting it when the season changed
+    pub fn record_season_earnings(&mut self, season: u8, year: u32, coins: u64) -> u64 {
+        if self.earnings_season != season || self.earnings_year != year {
+            self.earnings_season = season;
+            self.earnings_year = year;
+            self.season_earnings = 0;
         }
-        Some(&self.farm_tiles[row * 5 + col])
+        self.season_earnings = self.season_earnings.saturating_add(coins);
+        self.season_earnings
+    }
+
+    pub fn tool(&self, kind: ToolKind) -> &Tool {
+        &self.tools[kind as usize]
+    }
+
+    pub fn tool_mut(&mut self, kind: ToolKind) -> &mut Tool {
+        &mut self.tools[kind as usize]
     }
 
-    /// Check if a plot at (row, col) has a mature crop ready to harvest
-    pub fn is_plot_harvestable(&self, row: usize, col: usize, current_time: i64) -> bool {
-        if let Some(plot) = self.get_plot(row, col) {
-            if plot.crop_type != 0 {
-                if let Ok(config) = crate::state::get_crop_config(plot.crop_type) {
-                    let mature_at = plot.planted_at + config.growth_time;
-                    return current_time >= mature_at;
+    /// Craft a tool up to its next tier from resources; it comes back at full durability
+    pub fn upgrade_tool(&mut self, kind: ToolKind) -> Result<Tool> {
+        let next_tier = self.tool(kind).tier.next().ok_or(FarmingError::ToolMaxTier)?;
+        require!(self.level >= next_tier.required_level(), FarmingError::LevelTooLow);
+        self.consume_resources(&next_tier.upgrade_cost())?;
+
+        let tool = Tool::new(next_tier);
+        *self.tool_mut(kind) = tool;
+        // A bigger can is delivered full
+        if kind == ToolKind::WateringCan {
+            self.refill_watering_can();
+        }
+        Ok(tool)
+    }
+
+    /// Craft a repair from resources, restoring full durability for the tool's tier
+    pub fn repair_tool(&mut self, kind: ToolKind) -> Result<Tool> {
+        let tier = self.tool(kind).tier;
+        require!(self.tool(kind).durability < tier.max_durability(), FarmingError::ToolAtFullDurability);
+        self.consume_resources(&tier.repair_cost())?;
+
+        let tool = Tool::new(tier);
+        *self.tool_mut(kind) = tool;
+        Ok(tool)
+    }
+
+    /// Fill the watering can to its tier's capacity
+    pub fn refill_watering_can(&mut self) {
+        self.watering_can_uses = self.tool(ToolKind::WateringCan).tier.can_capacity();
+    }
+
+    /// Number of compost bins that have state slots
+    pub fn active_compost_bins(&self) -> usize {
+        (self.inventory.amount(ItemId::CompostBin) as usize).min(MAX_COMPOST_BINS)
+    }
+
+    /// Fail unless `quantity` more of a crafted item fits in inventory
+    pub fn ensure_room_for(&self, item: CraftableItem, quantity: u16) -> Result<()> {
+        if let Some(item_id) = item.item() {
+            if self.inventory.free_capacity(item_id) < quantity {
+                return Err(match item_id {
+                    ItemId::CompostBin => FarmingError::TooManyCompostBins,
+                    _ => FarmingError::ResourceStackOverflow,
                 }
+                .into());
             }
         }
-        false
+        Ok(())
+    }
+
+    /// Add crafted items to inventory (refills top up the watering can instead)
+    pub fn add_crafted_item(&mut self, item: CraftableItem, quantity: u16) -> Result<()> {
+        self.ensure_room_for(item, quantity)?;
+        match item.item() {
+            Some(item_id) => self.inventory.add(item_id, quantity),
+            None => {
+                self.refill_watering_can();
+                Ok(())
+            }
+        }
+    }
+
+    /// Crafted items of a kind currently held (refills report 0)
+    pub fn crafted_item_count(&self, item: CraftableItem) -> u16 {
+        item.item().map_or(0, |item_id| self.inventory.amount(item_id))
+    }
+
+    pub fn has_unlocked_recipe(&self, item_id: u8) -> bool {
+        (item_id as usize) < MAX_RECIPES && self.unlocked_recipes & (1 << item_id) != 0
+    }
+
+    pub fn unlock_recipe(&mut self, item_id: u8) {
+        self.unlocked_recipes |= 1 << item_id;
+    }
+
+    /// Remove one crafted structure from inventory (used when exporting it as an asset).
+    /// An exported compost bin is the last one held and leaves with its contents.
+    pub fn remove_crafted_item(&mut self, item: CraftableItem) -> Result<()> {
+        require!(item.is_exportable(), FarmingError::ItemNotExportable);
+        let item_id = item.item().ok_or(FarmingError::ItemNotExportable)?;
+        self.inventory.remove(item_id, 1)?;
+        if item_id == ItemId::CompostBin {
+            let remaining = self.active_compost_bins();
+            self.compost_bins[remaining] = CompostBin::default();
+        }
+        Ok(())
     }
 }
 
@@ -198,8 +277,12 @@ pub struct FarmTile {
     pub planted_at: i64,
     pub fertility: u8,
     pub last_crop_type: u8,
-    pub restorative_bonus_used: bool,
     pub planted_in_season: Option<u8>,
+    pub crop_history: RotationHistory,
+    pub nitrogen_bonus: u8,             // Pending soil nitrogen from a nearby restorative harvest (0 = none or consumed)
+    pub fallow_since: i64,              // When the tile was last harvested or cleared (0 = not fallow)
+    pub soil: SoilProfile,
+    pub fertilizer: FertilizerState,
 }
 
 impl FarmTile {
@@ -207,6 +290,240 @@ impl FarmTile {
         + 8 // planted_at
         + 1 // fertility
         + 1 // last_crop_type
-        + 1 // restorative_bonus_used
-        + 2; // planted_in_season (Option<u8> = 1 tag + 1 value)
+        + 2 // planted_in_season (Option<u8> = 1 tag + 1 value)
+        + RotationHistory::SPACE // crop_history
+        + 1 // nitrogen_bonus
+        + 8 // fallow_since
+        + SoilProfile::SPACE // soil
+        + FertilizerState::SPACE; // fertilizer
+
+    /// Leave a nitrogen boost on this tile; a stronger pending boost is kept as is
+    pub fn deposit_nitrogen(&mut self, amount: u8) {
+        self.nitrogen_bonus = self.nitrogen_bonus.max(amount);
+    }
+
+    /// Credit fertility regained while the tile lay fallow, up to `cap`, and let the soil's
+    /// nutrients recover by the same number of points. Leftover time below one `interval`
+    /// carries over to the next call.
+    pub fn apply_fallow_regeneration(&mut self, current_time: i64, interval: i64, cap: u8) -> u8 {
+        if self.crop_type != 0 || self.fallow_since <= 0 || interval <= 0 {
+            return 0;
+        }
+
+        let points = current_time.saturating_sub(self.fallow_since) / interval;
+        if points <= 0 {
+            return 0;
+        }
+        self.fallow_since = self.fallow_since.saturating_add(points.saturating_mul(interval));
+
+        let points = points.min(u8::MAX as i64) as u8;
+        if !self.soil.is_unset() {
+            self.soil.regenerate(points);
+        }
+
+        let headroom = cap.min(MAX_FERTILITY).saturating_sub(self.fertility);
+        let gain = points.min(headroom);
+        self.fertility += gain;
+        gain
+    }
+
+    /// Work the pending nitrogen boost into the soil, returning the nitrogen it added
+    pub fn consume_nitrogen(&mut self) -> Option<u8> {
+        if self.nitrogen_bonus == 0 {
+            return None;
+        }
+
+        let boost = self.nitrogen_bonus;
+        self.soil.nitrogen = self.soil.nitrogen.saturating_add(boost).min(MAX_SOIL_LEVEL);
+        self.nitrogen_bonus = 0;
+        Some(boost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{DEFAULT_PLAYER_FERTILITY, GRID_SIZE};
+
+    fn player() -> PlayerAccount {
+        let zeroed = vec![0u8; PlayerAccount::space(0)];
+        let mut player = PlayerAccount::try_deserialize_unchecked(&mut zeroed.as_slice()).unwrap();
+        player.init(Pubkey::new_unique(), 255, FarmGrid::new(GRID_SIZE as u8, DEFAULT_PLAYER_FERTILITY));
+        player
+    }
+
+    #[test]
+    fn exported_structures_round_trip_through_inventory() {
+        let mut player = player();
+        player.add_crafted_item(CraftableItem::Scarecrow, 1).unwrap();
+
+        player.remove_crafted_item(CraftableItem::Scarecrow).unwrap();
+        assert_eq!(player.crafted_item_count(CraftableItem::Scarecrow), 0);
+        assert!(player.remove_crafted_item(CraftableItem::Scarecrow).is_err());
+
+        player.add_crafted_item(CraftableItem::Scarecrow, 1).unwrap();
+        assert_eq!(player.crafted_item_count(CraftableItem::Scarecrow), 1);
+    }
+
+    #[test]
+    fn compost_bins_are_capped_and_exports_clear_their_slot() {
+        let mut player = player();
+        player.add_crafted_item(CraftableItem::CompostBin, MAX_COMPOST_BINS as u16).unwrap();
+        assert!(player.ensure_room_for(CraftableItem::CompostBin, 1).is_err());
+        assert!(player.add_crafted_item(CraftableItem::CompostBin, 1).is_err());
+        assert_eq!(player.active_compost_bins(), MAX_COMPOST_BINS);
+
+        let used = CompostBin { fill: 20, stored: 2, ..CompostBin::default() };
+        player.compost_bins = [used; MAX_COMPOST_BINS];
+        player.remove_crafted_item(CraftableItem::CompostBin).unwrap();
+        assert_eq!(player.active_compost_bins(), MAX_COMPOST_BINS - 1);
+        assert_eq!(player.compost_bins[MAX_COMPOST_BINS - 1], CompostBin::default());
+        assert_eq!(player.compost_bins[0], used);
+
+        // A bin brought back starts empty
+        player.add_crafted_item(CraftableItem::CompostBin, 1).unwrap();
+        assert_eq!(player.compost_bins[MAX_COMPOST_BINS - 1], CompostBin::default());
+    }
+
+    #[test]
+    fn tool_upgrades_need_level_and_resources() {
+        let mut player = player();
+        // Starter kit: 10 wood + 5 stone, topped up to the copper cost
+        player.add_resources(&[(1, 5)]).unwrap();
+
+        // Copper needs level 3
+        assert!(player.upgrade_tool(ToolKind::Axe).is_err());
+        player.level = ToolTier::Copper.required_level();
+
+        let tool = player.upgrade_tool(ToolKind::Axe).unwrap();
+        assert_eq!(tool, Tool::new(ToolTier::Copper));
+        assert_eq!(*player.tool(ToolKind::Axe), tool);
+        assert_eq!(player.resource_amount(ResourceType::Wood), 0);
+
+        // Iron is out of reach on both level and resources; nothing changes
+        let stone = player.resource_amount(ResourceType::Stone);
+        assert!(player.upgrade_tool(ToolKind::Axe).is_err());
+        assert_eq!(player.tool(ToolKind::Axe).tier, ToolTier::Copper);
+        assert_eq!(player.resource_amount(ResourceType::Stone), stone);
+    }
+
+    #[test]
+    fn upgraded_watering_can_is_delivered_full() {
+        let mut player = player();
+        player.level = ToolTier::Copper.required_level();
+        player.add_resources(&[(0, 10), (1, 10)]).unwrap();
+        player.watering_can_uses = 0;
+
+        player.upgrade_tool(ToolKind::WateringCan).unwrap();
+        assert_eq!(player.watering_can_uses, ToolTier::Copper.can_capacity());
+    }
+
+    #[test]
+    fn repairs_restore_worn_tools_only() {
+        let mut player = player();
+        player.add_resources(&[(0, 10)]).unwrap();
+        assert!(player.repair_tool(ToolKind::Scythe).is_err());
+
+        let scythe = player.tool_mut(ToolKind::Scythe);
+        scythe.wear();
+        scythe.wear();
+        assert_eq!(scythe.durability, ToolTier::Basic.max_durability() - 2);
+
+        let wood = player.resource_amount(ResourceType::Wood);
+        let tool = player.repair_tool(ToolKind::Scythe).unwrap();
+        assert_eq!(tool.durability, ToolTier::Basic.max_durability());
+        assert_eq!(player.resource_amount(ResourceType::Wood), wood - 2);
+    }
+
+    #[test]
+    fn season_earnings_restart_when_the_season_rolls_over() {
+        let mut player = player();
+        assert_eq!(player.record_season_earnings(0, 0, 40), 40);
+        assert_eq!(player.record_season_earnings(0, 0, 60), 100);
+
+        // Next season, then the same season a year later
+        assert_eq!(player.record_season_earnings(1, 0, 25), 25);
+        assert_eq!(player.record_season_earnings(1, 1, 10), 10);
+        assert_eq!((player.earnings_season, player.earnings_year), (1, 1));
+    }
+
+    #[test]
+    fn tools_cannot_be_exported() {
+        let mut player = player();
+        player.add_crafted_item(CraftableItem::AdvancedTool, 1).unwrap();
+        assert!(!CraftableItem::AdvancedTool.is_exportable());
+        assert!(player.remove_crafted_item(CraftableItem::AdvancedTool).is_err());
+        assert_eq!(player.crafted_item_count(CraftableItem::AdvancedTool), 1);
+    }
+
+    #[test]
+    fn consumables_cannot_be_exported() {
+        let mut player = player();
+        player.add_crafted_item(CraftableItem::Fertilizer, 1).unwrap();
+        let held = player.crafted_item_count(CraftableItem::Fertilizer);
+        assert!(!CraftableItem::Fertilizer.is_exportable());
+        assert!(player.remove_crafted_item(CraftableItem::Fertilizer).is_err());
+        assert_eq!(player.crafted_item_count(CraftableItem::Fertilizer), held);
+    }
+
+    #[test]
+    fn fallow_regeneration_carries_partial_intervals() {
+        let mut tile = FarmTile { fertility: 50, fallow_since: 1_000, ..FarmTile::default() };
+
+        // 2.5 intervals: two points now, the half interval is kept for later
+        assert_eq!(tile.apply_fallow_regeneration(1_250, 100, 80), 2);
+        assert_eq!(tile.fertility, 52);
+        assert_eq!(tile.fallow_since, 1_200);
+
+        // The carried half plus another half makes one more point
+        assert_eq!(tile.apply_fallow_regeneration(1_300, 100, 80), 1);
+        assert_eq!(tile.fertility, 53);
+        assert_eq!(tile.apply_fallow_regeneration(1_350, 100, 80), 0);
+    }
+
+    #[test]
+    fn fallow_regeneration_stops_at_the_cap_and_on_planted_tiles() {
+        let mut tile = FarmTile { fertility: 78, fallow_since: 1, ..FarmTile::default() };
+        assert_eq!(tile.apply_fallow_regeneration(10_001, 100, 80), 2);
+        assert_eq!(tile.fertility, 80);
+
+        let mut planted = FarmTile { crop_type: 1, fertility: 50, fallow_since: 1, ..FarmTile::default() };
+        assert_eq!(planted.apply_fallow_regeneration(10_001, 100, 80), 0);
+
+        let mut never_fallow = FarmTile { fertility: 50, ..FarmTile::default() };
+        assert_eq!(never_fallow.apply_fallow_regeneration(10_001, 100, 80), 0);
+    }
+
+    #[test]
+    fn fallow_tiles_recover_nutrients_past_the_fertility_cap() {
+        let soil = SoilProfile { nitrogen: 20, ..SoilProfile::initial() };
+        let mut tile = FarmTile { fertility: 80, fallow_since: 1, soil, ..FarmTile::default() };
+        assert_eq!(tile.apply_fallow_regeneration(1_001, 100, 80), 0);
+        assert_eq!(tile.soil.nitrogen, 30);
+    }
+
+    #[test]
+    fn stronger_pending_nitrogen_wins() {
+        let mut tile = FarmTile::default();
+        tile.deposit_nitrogen(8);
+        tile.deposit_nitrogen(15);
+        tile.deposit_nitrogen(8);
+        assert_eq!(tile.nitrogen_bonus, 15);
+    }
+
+    #[test]
+    fn nitrogen_boost_feeds_the_soil_once() {
+        let mut tile = FarmTile { soil: SoilProfile::initial(), ..FarmTile::default() };
+        let before = tile.soil.nitrogen;
+        tile.deposit_nitrogen(15);
+
+        assert_eq!(tile.consume_nitrogen(), Some(15));
+        assert_eq!(tile.soil.nitrogen, before + 15);
+        assert_eq!(tile.consume_nitrogen(), None);
+
+        tile.soil.nitrogen = MAX_SOIL_LEVEL - 5;
+        tile.deposit_nitrogen(15);
+        assert_eq!(tile.consume_nitrogen(), Some(15));
+        assert_eq!(tile.soil.nitrogen, MAX_SOIL_LEVEL);
+    }
 }
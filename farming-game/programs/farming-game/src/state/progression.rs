@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{GRID_SIZE, LEVEL_UP_COIN_REWARD, MAX_FIELDS, MAX_GRID_SIZE, MAX_LEVEL, XP_LEVEL_STEP};
+use crate::events::LevelUp;
+use crate::state::crop::CropType;
+use crate::state::player::PlayerAccount;
+use crate::state::recipes::CraftableItem;
+use crate::state::tools::ToolTier;
+
+/// Total XP needed to reach `level` (level 1 needs none; each level costs one more step than the last)
+pub fn xp_for_level(level: u8) -> u64 {
+    let level = level.clamp(1, MAX_LEVEL) as u64;
+    XP_LEVEL_STEP * (level - 1) * level / 2
+}
+
+/// Level reached with `xp` total experience
+pub fn level_for_xp(xp: u64) -> u8 {
+    let mut level = 1;
+    while level < MAX_LEVEL && xp >= xp_for_level(level + 1) {
+        level += 1;
+    }
+    level
+}
+
+/// Level needed to expand a farm or field to `grid_size` x `grid_size`
+pub fn expansion_required_level(grid_size: u8) -> u8 {
+    match grid_size {
+        0..=5 => 1,
+        6 => 4,
+        7 => 8,
+        _ => 12,
+    }
+}
+
+/// Level needed to buy extra field `field_id` (1..=MAX_FIELDS)
+pub fn field_required_level(field_id: u8) -> u8 {
+    field_id.saturating_mul(5)
+}
+
+/// Content that becomes available on reaching a level
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LevelRewards {
+    pub coins: u64,
+    pub crops: Vec<u8>,
+    pub recipes: Vec<u8>,
+    pub tool_tiers: Vec<u8>,
+    pub grid_size: Option<u8>,   // New maximum farm size unlocked
+    pub field_id: Option<u8>,    // Extra field that can now be bought
+}
+
+impl LevelRewards {
+    pub fn for_level(level: u8) -> Self {
+        let crops = (1..=5u8)
+            .filter_map(CropType::from_u8)
+            .filter(|crop| crop.required_level() == level)
+            .map(|crop| crop as u8)
+            .collect();
+        // Launch recipe levels; admins may have moved them in the recipe registry
+        let recipes = CraftableItem::ALL
+            .iter()
+            .filter(|item| item.required_level() == level)
+            .map(|item| *item as u8)
+            .collect();
+        let tool_tiers = [ToolTier::Basic, ToolTier::Copper, ToolTier::Iron, ToolTier::Gold]
+            .iter()
+            .filter(|tier| tier.required_level() == level)
+            .map(|tier| *tier as u8)
+            .collect();
+        let grid_size = (GRID_SIZE as u8 + 1..=MAX_GRID_SIZE).find(|size| expansion_required_level(*size) == level);
+        let field_id = (1..=MAX_FIELDS).find(|id| field_required_level(*id) == level);
+
+        Self {
+            coins: LEVEL_UP_COIN_REWARD.saturating_mul(level as u64),
+            crops,
+            recipes,
+            tool_tiers,
+            grid_size,
+            field_id,
+        }
+    }
+}
+
+/// Add XP, pay level-up coin rewards and emit a LevelUp for each level gained
+pub fn award_xp(player_account: &mut PlayerAccount, player: Pubkey, amount: u64) {
+    if amount == 0 {
+        return;
+    }
+    player_account.xp = player_account.xp.saturating_add(amount);
+
+    let new_level = level_for_xp(player_account.xp);
+    while player_account.level < new_level {
+        player_account.level += 1;
+        let rewards = LevelRewards::for_level(player_account.level);
+        player_account.coins = player_account.coins.saturating_add(rewards.coins);
+
+        emit!(LevelUp {
+            player,
+            level: player_account.level,
+            xp: player_account.xp,
+            coin_reward: rewards.coins,
+            unlocked_crops: rewards.crops,
+            unlocked_recipes: rewards.recipes,
+            unlocked_tool_tiers: rewards.tool_tiers,
+            unlocked_grid_size: rewards.grid_size,
+            unlocked_field_id: rewards.field_id,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn level_curve_round_trips() {
+        assert_eq!(level_for_xp(0), 1);
+        for level in 1..=MAX_LEVEL {
+            assert_eq!(level_for_xp(xp_for_level(level)), level);
+            if level > 1 {
+                assert_eq!(level_for_xp(xp_for_level(level) - 1), level - 1);
+            }
+        }
+        assert_eq!(level_for_xp(u64::MAX), MAX_LEVEL);
+    }
+
+    #[test]
+    fn every_gate_is_reachable() {
+        for crop in (1..=5u8).filter_map(CropType::from_u8) {
+            assert!((1..=MAX_LEVEL).contains(&crop.required_level()));
+        }
+        for item in CraftableItem::ALL {
+            assert!((1..=MAX_LEVEL).contains(&item.required_level()));
+        }
+        for size in 6..=MAX_GRID_SIZE {
+            assert!(expansion_required_level(size) <= MAX_LEVEL);
+        }
+        for id in 1..=MAX_FIELDS {
+            assert!(field_required_level(id) <= MAX_LEVEL);
+        }
+    }
+
+    #[test]
+    fn level_rewards_list_new_unlocks() {
+        let rewards = LevelRewards::for_level(3);
+        assert!(rewards.crops.contains(&(CropType::Tomato as u8)));
+        assert!(rewards.tool_tiers.contains(&(ToolTier::Copper as u8)));
+        assert_eq!(LevelRewards::for_level(4).grid_size, Some(6));
+        assert_eq!(LevelRewards::for_level(5).field_id, Some(1));
+    }
+}
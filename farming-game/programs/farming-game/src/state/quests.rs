@@ -0,0 +1,303 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    CROP_TYPE_COUNT, DAILY_QUESTS, QUEST_DAILY_COINS, QUEST_DAILY_XP, QUEST_WEEKLY_COINS, QUEST_WEEKLY_XP, QUEST_WEEK_DAYS, WEEKLY_QUESTS,
+};
+use crate::errors::FarmingError;
+use crate::events::QuestCompleted;
+use crate::state::crop::CropType;
+use crate::state::player::PlayerAccount;
+use crate::state::progression::award_xp;
+use crate::state::synergy::PatternType;
+
+/// Quest target meaning "any item"
+pub const ANY_TARGET: u8 = u8::MAX;
+
+/// Patterns offered by "form a pattern" quests (ones a player can set up within a day)
+const QUEST_PATTERNS: [PatternType; 6] = [
+    PatternType::MonocultureRow,
+    PatternType::MonocultureBlock,
+    PatternType::CompanionPlanting,
+    PatternType::CropDiversity,
+    PatternType::Checkerboard,
+    PatternType::DiagonalLine,
+];
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum QuestKind {
+    HarvestCrop = 0,       // target = crop type
+    FormPattern = 1,       // target = PatternType, counted at harvest
+    GatherResource = 2,    // target = resource type
+    CraftItem = 3,         // target = ANY_TARGET
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Quest {
+    pub kind: u8,
+    pub target: u8,
+    pub goal: u16,          // 0 = empty slot
+    pub progress: u16,
+    pub claimed: bool,
+}
+
+impl Quest {
+    pub const SPACE: usize = 1 // kind
+        + 1 // target
+        + 2 // goal
+        + 2 // progress
+        + 1; // claimed
+
+    pub fn is_complete(&self) -> bool {
+        self.goal > 0 && self.progress >= self.goal
+    }
+
+    /// Derive a quest from a 64-bit seed; weekly quests ask for more.
+    /// Harvest quests only name crops a player of `level` can plant.
+    fn generate(seed: u64, weekly: bool, level: u8) -> Self {
+        let roll = (seed >> 8) as u16;
+        let crops: Vec<u8> = (1..=CROP_TYPE_COUNT as u8)
+            .filter(|crop| CropType::from_u8(*crop).is_some_and(|crop| crop.required_level() <= level))
+            .collect();
+        let (kind, target, goal) = match seed % 4 {
+            0 => (QuestKind::HarvestCrop, crops[roll as usize % crops.len()], 3 + roll % 3),
+            1 => (
+                QuestKind::FormPattern,
+                QUEST_PATTERNS[roll as usize % QUEST_PATTERNS.len()] as u8,
+                1,
+            ),
+            2 => (QuestKind::GatherResource, (roll % 3) as u8, 10 + roll % 11),
+            _ => (QuestKind::CraftItem, ANY_TARGET, 2 + roll % 2),
+        };
+        let goal = if weekly { goal * 5 } else { goal };
+
+        Self {
+            kind: kind as u8,
+            target,
+            goal,
+            progress: 0,
+            claimed: false,
+        }
+    }
+}
+
+/// Per-player quests: daily slots refresh each day, weekly slots every QUEST_WEEK_DAYS
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct QuestBoard {
+    pub day: u32,
+    pub week: u32,
+    pub daily: [Quest; DAILY_QUESTS],
+    pub weekly: [Quest; WEEKLY_QUESTS],
+}
+
+impl QuestBoard {
+    pub const SPACE: usize = 4 // day
+        + 4 // week
+        + Quest::SPACE * DAILY_QUESTS // daily
+        + Quest::SPACE * WEEKLY_QUESTS; // weekly
+
+    /// Deterministic per-player seed for a day and slot (mixing only, not a secure random source)
+    fn seed(owner: &Pubkey, period: u32, slot: usize, weekly: bool) -> u64 {
+        let mut seed = (u64::from(period) << 8) | ((slot as u64) << 1) | weekly as u64;
+        for chunk in owner.to_bytes().chunks(8) {
+            seed ^= u64::from_le_bytes(chunk.try_into().unwrap_or_default());
+            seed = splitmix64(seed);
+        }
+        seed
+    }
+
+    /// Regenerate quests that are stale for `days_passed` (an empty board is always generated).
+    /// Unclaimed rewards on a stale board are forfeited.
+    pub fn refresh(&mut self, owner: &Pubkey, days_passed: u32, level: u8) -> bool {
+        let week = days_passed / QUEST_WEEK_DAYS;
+        let fresh_board = self.daily[0].goal == 0;
+        let mut refreshed = false;
+
+        if fresh_board || self.day != days_passed {
+            self.day = days_passed;
+            for (slot, quest) in self.daily.iter_mut().enumerate() {
+                *quest = Quest::generate(Self::seed(owner, days_passed, slot, false), false, level);
+            }
+            refreshed = true;
+        }
+        if fresh_board || self.week != week {
+            self.week = week;
+            for (slot, quest) in self.weekly.iter_mut().enumerate() {
+                *quest = Quest::generate(Self::seed(owner, week, slot, true), true, level);
+            }
+            refreshed = true;
+        }
+        refreshed
+    }
+
+    /// Quest by board index: daily slots first, then weekly
+    pub fn quest_mut(&mut self, index: u8) -> Option<(&mut Quest, bool)> {
+        let index = index as usize;
+        if index < DAILY_QUESTS {
+            Some((&mut self.daily[index], false))
+        } else {
+            self.weekly.get_mut(index - DAILY_QUESTS).map(|quest| (quest, true))
+        }
+    }
+
+    /// Add progress to matching quests; returns board indices that just completed
+    pub fn record(&mut self, kind: QuestKind, target: u8, amount: u16) -> Vec<u8> {
+        let mut completed = Vec::new();
+        for (index, quest) in self.daily.iter_mut().chain(self.weekly.iter_mut()).enumerate() {
+            let matches = quest.kind == kind as u8 && (quest.target == target || quest.target == ANY_TARGET);
+            if quest.goal == 0 || !matches || quest.is_complete() {
+                continue;
+            }
+            quest.progress = quest.progress.saturating_add(amount).min(quest.goal);
+            if quest.is_complete() {
+                completed.push(index as u8);
+            }
+        }
+        completed
+    }
+}
+
+/// Coins and XP paid for a claimed quest
+pub fn quest_reward(weekly: bool) -> (u64, u64) {
+    if weekly {
+        (QUEST_WEEKLY_COINS, QUEST_WEEKLY_XP)
+    } else {
+        (QUEST_DAILY_COINS, QUEST_DAILY_XP)
+    }
+}
+
+/// Refresh the board for today, then credit progress and emit QuestCompleted for finished quests
+pub fn track_quest(
+    player_account: &mut PlayerAccount,
+    player: Pubkey,
+    days_passed: u32,
+    kind: QuestKind,
+    target: u8,
+    amount: u16,
+) {
+    let owner = player_account.owner;
+    let level = player_account.level;
+    player_account.quests.refresh(&owner, days_passed, level);
+    for index in player_account.quests.record(kind, target, amount) {
+        emit!(QuestCompleted {
+            player,
+            day: days_passed,
+            quest_index: index,
+            kind: kind as u8,
+            target,
+        });
+    }
+}
+
+/// Claim a completed quest's reward. The board is refreshed first, so only quests
+/// from the current day (or week) can be claimed.
+pub fn claim_quest_reward(
+    player_account: &mut PlayerAccount,
+    player: Pubkey,
+    days_passed: u32,
+    index: u8,
+) -> Result<(u64, u64)> {
+    let owner = player_account.owner;
+    let level = player_account.level;
+    player_account.quests.refresh(&owner, days_passed, level);
+
+    let (quest, weekly) = player_account
+        .quests
+        .quest_mut(index)
+        .ok_or(FarmingError::InvalidQuestIndex)?;
+    require!(quest.is_complete(), FarmingError::QuestNotComplete);
+    require!(!quest.claimed, FarmingError::QuestAlreadyClaimed);
+    quest.claimed = true;
+
+    let (coins, xp) = quest_reward(weekly);
+    player_account.coins = player_account.coins.saturating_add(coins);
+    award_xp(player_account, player, xp);
+    Ok((coins, xp))
+}
+
+fn splitmix64(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{DEFAULT_PLAYER_FERTILITY, GRID_SIZE};
+    use crate::state::field::FarmGrid;
+
+    #[test]
+    fn boards_are_deterministic_per_player_and_day() {
+        let owner = Pubkey::new_from_array([7; 32]);
+        let other = Pubkey::new_from_array([9; 32]);
+
+        let mut a = QuestBoard::default();
+        let mut b = QuestBoard::default();
+        assert!(a.refresh(&owner, 3, 1));
+        b.refresh(&owner, 3, 1);
+        assert_eq!(a, b);
+        assert!(!a.refresh(&owner, 3, 1));
+
+        let mut c = QuestBoard::default();
+        c.refresh(&other, 3, 1);
+        let mut d = QuestBoard::default();
+        d.refresh(&owner, 4, 1);
+        assert!(c.daily != a.daily || d.daily != a.daily);
+    }
+
+    #[test]
+    fn weekly_quests_survive_a_day_rollover() {
+        let owner = Pubkey::new_from_array([1; 32]);
+        let mut board = QuestBoard::default();
+        board.refresh(&owner, QUEST_WEEK_DAYS, 1);
+        let weekly = board.weekly;
+        board.refresh(&owner, QUEST_WEEK_DAYS + 1, 1);
+        assert_eq!(board.weekly, weekly);
+        board.refresh(&owner, QUEST_WEEK_DAYS * 2, 1);
+        assert_eq!(board.week, 2);
+    }
+
+    #[test]
+    fn harvest_quests_only_name_plantable_crops() {
+        for seed in (0..4_000u64).map(|n| n * 4) {
+            let quest = Quest::generate(seed, false, 1);
+            assert_eq!(quest.kind, QuestKind::HarvestCrop as u8);
+            let crop = CropType::from_u8(quest.target).unwrap();
+            assert_eq!(crop.required_level(), 1);
+        }
+        let targets: Vec<u8> = (0..4_000u64).map(|n| Quest::generate(n * 4, false, 10).target).collect();
+        assert!(targets.contains(&(CropType::Corn as u8)));
+    }
+
+    #[test]
+    fn claims_only_cover_the_current_board() {
+        let zeroed = vec![0u8; PlayerAccount::space(0)];
+        let mut player = PlayerAccount::try_deserialize_unchecked(&mut zeroed.as_slice()).unwrap();
+        player.init(Pubkey::new_unique(), 255, FarmGrid::new(GRID_SIZE as u8, DEFAULT_PLAYER_FERTILITY));
+        let owner = player.owner;
+        player.quests.refresh(&owner, 2, player.level);
+        player.quests.daily[0].progress = player.quests.daily[0].goal;
+
+        // Completed yesterday and never claimed: the new day's board replaces it
+        assert!(claim_quest_reward(&mut player, owner, 3, 0).is_err());
+        assert_eq!(player.quests.day, 3);
+
+        player.quests.daily[0].progress = player.quests.daily[0].goal;
+        let coins = player.coins;
+        assert_eq!(claim_quest_reward(&mut player, owner, 3, 0).unwrap().0, QUEST_DAILY_COINS);
+        assert_eq!(player.coins, coins + QUEST_DAILY_COINS);
+        assert!(claim_quest_reward(&mut player, owner, 3, 0).is_err());
+    }
+
+    #[test]
+    fn progress_completes_once_and_caps_at_goal() {
+        let mut board = QuestBoard::default();
+        board.daily[0] = Quest { kind: QuestKind::GatherResource as u8, target: 2, goal: 10, progress: 0, claimed: false };
+        assert!(board.record(QuestKind::GatherResource, 1, 10).is_empty());
+        assert_eq!(board.record(QuestKind::GatherResource, 2, 25), vec![0]);
+        assert_eq!(board.daily[0].progress, 10);
+        assert!(board.record(QuestKind::GatherResource, 2, 5).is_empty());
+    }
+}
@@ -0,0 +1,221 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_RECIPES, MAX_RECIPE_BYPRODUCTS, MAX_RECIPE_INPUTS};
+use crate::errors::FarmingError;
+use crate::state::inventory::{ItemId, ItemStack};
+use crate::state::player::PlayerAccount;
+use crate::state::recipes::CraftableItem;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Recipe {
+    pub enabled: bool,
+    pub inputs: [ItemStack; MAX_RECIPE_INPUTS],          // Empty stacks are unused slots
+    pub output_quantity: u16,
+    pub byproducts: [ItemStack; MAX_RECIPE_BYPRODUCTS],  // Extra outputs credited alongside the item
+    pub crafting_time: i64,                 // Seconds (0 = instant)
+    pub required_structure: Option<u8>,     // Crafted item id the player must own
+    pub min_crafts: u16,                    // Crafts the player must have completed
+    pub required_level: u8,                 // Player level needed to unlock or craft
+}
+
+impl Recipe {
+    pub const SPACE: usize = 1 // enabled
+        + ItemStack::SPACE * MAX_RECIPE_INPUTS // inputs
+        + 2 // output_quantity
+        + ItemStack::SPACE * MAX_RECIPE_BYPRODUCTS // byproducts
+        + 8 // crafting_time
+        + 2 // required_structure (Option<u8> = 1 tag + 1 value)
+        + 2 // min_crafts
+        + 1; // required_level
+
+    /// Launch recipe for an item, taken from the built-in tables
+    pub fn default_for(item: CraftableItem) -> Self {
+        let mut inputs = [ItemStack::default(); MAX_RECIPE_INPUTS];
+        for (slot, (item_id, amount)) in inputs.iter_mut().zip(item.get_recipe_inputs()) {
+            *slot = ItemStack { item_id, amount };
+        }
+        let mut byproducts = [ItemStack::default(); MAX_RECIPE_BYPRODUCTS];
+        for (slot, (item_id, amount)) in byproducts.iter_mut().zip(item.get_byproducts()) {
+            *slot = ItemStack { item_id, amount };
+        }
+        let (required_structure, min_crafts) = item.default_prerequisites();
+
+        Self {
+            enabled: true,
+            inputs,
+            output_quantity: item.get_output_quantity(),
+            byproducts,
+            crafting_time: item.get_crafting_time(),
+            required_structure: required_structure.map(|structure| structure as u8),
+            min_crafts,
+            required_level: item.required_level(),
+        }
+    }
+
+    /// Inputs as (item_id, amount) pairs, skipping unused slots
+    pub fn input_costs(&self) -> Vec<(u8, u16)> {
+        stack_list(&self.inputs)
+    }
+
+    /// Byproducts as (item_id, amount) pairs, skipping unused slots
+    pub fn byproduct_list(&self) -> Vec<(u8, u16)> {
+        stack_list(&self.byproducts)
+    }
+
+    pub fn is_instant(&self) -> bool {
+        self.crafting_time == 0
+    }
+
+    /// Recipes without prerequisites are available without unlocking
+    pub fn is_starter(&self) -> bool {
+        self.required_structure.is_none() && self.min_crafts == 0
+    }
+
+    pub fn prerequisites_met(&self, player: &PlayerAccount) -> Result<bool> {
+        if player.crafts_completed < self.min_crafts {
+            return Ok(false);
+        }
+        if let Some(structure) = self.required_structure {
+            let item = CraftableItem::from_u8(structure)?;
+            if player.crafted_item_count(item) == 0 {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        require!(self.output_quantity > 0, FarmingError::InvalidRecipe);
+        require!(self.crafting_time >= 0, FarmingError::InvalidRecipe);
+        for (item_id, _) in self.input_costs().into_iter().chain(self.byproduct_list()) {
+            ItemId::from_u8(item_id)?;
+        }
+        if let Some(structure) = self.required_structure {
+            CraftableItem::from_u8(structure)?;
+        }
+        Ok(())
+    }
+}
+
+fn stack_list(stacks: &[ItemStack]) -> Vec<(u8, u16)> {
+    stacks
+        .iter()
+        .filter(|stack| stack.amount > 0)
+        .map(|stack| (stack.item_id, stack.amount))
+        .collect()
+}
+
+/// Global, admin-editable crafting recipes indexed by item id
+#[account]
+pub struct RecipeRegistry {
+    pub authority: Pubkey,
+    pub recipes: [Recipe; MAX_RECIPES],
+    pub bump: u8,
+}
+
+impl RecipeRegistry {
+    pub const SPACE: usize = 8 // discriminator
+        + 32 // authority
+        + Recipe::SPACE * MAX_RECIPES // recipes
+        + 1; // bump
+
+    /// Enabled recipe for an item id; used when starting a craft or unlocking
+    pub fn recipe(&self, item_id: u8) -> Result<&Recipe> {
+        let recipe = self.get(item_id)?;
+        require!(recipe.enabled, FarmingError::RecipeDisabled);
+        Ok(recipe)
+    }
+
+    /// Recipe for an item id whether or not it is enabled, so jobs queued
+    /// before a recipe was disabled can still be finished
+    pub fn get(&self, item_id: u8) -> Result<&Recipe> {
+        self.recipes
+            .get(item_id as usize)
+            .ok_or(FarmingError::InvalidCraftableItem.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{DEFAULT_PLAYER_FERTILITY, GRID_SIZE};
+    use crate::state::field::FarmGrid;
+
+    fn registry() -> RecipeRegistry {
+        let mut recipes = [Recipe::default(); MAX_RECIPES];
+        for item in CraftableItem::ALL {
+            recipes[item as usize] = Recipe::default_for(item);
+        }
+        RecipeRegistry { authority: Pubkey::new_unique(), recipes, bump: 255 }
+    }
+
+    fn player() -> PlayerAccount {
+        let zeroed = vec![0u8; PlayerAccount::space(0)];
+        let mut player = PlayerAccount::try_deserialize_unchecked(&mut zeroed.as_slice()).unwrap();
+        player.init(Pubkey::new_unique(), 255, FarmGrid::new(GRID_SIZE as u8, DEFAULT_PLAYER_FERTILITY));
+        player
+    }
+
+    #[test]
+    fn launch_recipes_match_the_item_tables() {
+        let registry = registry();
+        for item in CraftableItem::ALL {
+            let recipe = registry.recipe(item as u8).unwrap();
+            assert!(recipe.validate().is_ok());
+            assert_eq!(recipe.output_quantity, item.get_output_quantity());
+            assert_eq!(recipe.required_level, item.required_level());
+        }
+        assert!(registry.get(MAX_RECIPES as u8).is_err());
+    }
+
+    #[test]
+    fn disabled_recipes_stay_readable_for_queued_jobs() {
+        let mut registry = registry();
+        let id = CraftableItem::Scarecrow as u8;
+        registry.recipes[id as usize].enabled = false;
+
+        assert!(registry.recipe(id).is_err());
+        assert_eq!(registry.get(id).unwrap().output_quantity, CraftableItem::Scarecrow.get_output_quantity());
+    }
+
+    #[test]
+    fn updates_are_validated() {
+        let mut recipe = Recipe::default_for(CraftableItem::Fence);
+        recipe.output_quantity = 0;
+        assert!(recipe.validate().is_err());
+
+        let mut recipe = Recipe::default_for(CraftableItem::Fence);
+        recipe.inputs[0].item_id = u8::MAX;
+        assert!(recipe.validate().is_err());
+
+        let mut recipe = Recipe::default_for(CraftableItem::Fence);
+        recipe.required_structure = Some(u8::MAX);
+        assert!(recipe.validate().is_err());
+
+        let mut recipe = Recipe::default_for(CraftableItem::Fence);
+        recipe.required_level = 1;
+        recipe.crafting_time = 0;
+        assert!(recipe.validate().is_ok());
+        assert!(recipe.is_instant());
+    }
+
+    #[test]
+    fn unlocking_needs_the_structure_and_craft_count() {
+        let recipe = Recipe::default_for(CraftableItem::AdvancedTool);
+        assert!(!recipe.is_starter());
+        assert!(Recipe::default_for(CraftableItem::Plank).is_starter());
+
+        let mut player = player();
+        assert!(!recipe.prerequisites_met(&player).unwrap());
+
+        player.add_crafted_item(CraftableItem::CompostBin, 1).unwrap();
+        assert!(!recipe.prerequisites_met(&player).unwrap());
+
+        player.crafts_completed = recipe.min_crafts;
+        assert!(recipe.prerequisites_met(&player).unwrap());
+
+        assert!(!player.has_unlocked_recipe(CraftableItem::AdvancedTool as u8));
+        player.unlock_recipe(CraftableItem::AdvancedTool as u8);
+        assert!(player.has_unlocked_recipe(CraftableItem::AdvancedTool as u8));
+    }
+}
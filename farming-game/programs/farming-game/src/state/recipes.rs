@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::inventory::ItemId;
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(u8)]
 pub enum CraftableItem {
@@ -10,6 +12,9 @@ pub enum CraftableItem {
     Fence = 4,
     Sprinkler = 5,
     AdvancedTool = 6,
+    Plank = 7,
+    Brick = 8,
+    Rope = 9,
 }
 
 impl CraftableItem {
@@ -22,36 +27,79 @@ impl CraftableItem {
             4 => Ok(CraftableItem::Fence),
             5 => Ok(CraftableItem::Sprinkler),
             6 => Ok(CraftableItem::AdvancedTool),
+            7 => Ok(CraftableItem::Plank),
+            8 => Ok(CraftableItem::Brick),
+            9 => Ok(CraftableItem::Rope),
             _ => Err(error!(crate::errors::FarmingError::InvalidCraftableItem)),
         }
     }
 
     pub fn get_description(&self) -> &str {
         match self {
-            CraftableItem::WateringCanRefill => "Refills watering can to 10 uses",
+            CraftableItem::WateringCanRefill => "Refills watering can to its tier's capacity",
             CraftableItem::Fertilizer => "Creates 3 fertilizers for soil boosting",
-            CraftableItem::CompostBin => "Generates 1 fertilizer per day automatically",
+            CraftableItem::CompostBin => "Turns crop waste and fiber into compost, 1 per day",
             CraftableItem::Scarecrow => "Protects crops from pests",
             CraftableItem::Fence => "Increases max fertility cap to 110%",
             CraftableItem::Sprinkler => "Auto-waters adjacent plots daily",
             CraftableItem::AdvancedTool => "Waters 3x3 area with watering can",
+            CraftableItem::Plank => "Saws wood into planks for building",
+            CraftableItem::Brick => "Fires stone into bricks for building",
+            CraftableItem::Rope => "Twists fiber into rope, shaking loose a few seeds",
         }
     }
 
-    /// Get recipe inputs: Vec<(ResourceType, amount)>
+    /// Get recipe inputs: Vec<(ItemId, amount)>
     pub fn get_recipe_inputs(&self) -> Vec<(u8, u16)> {
-        // Returns as tuples: (resource_type_u8, amount)
+        // Returns as tuples: (item_id_u8, amount); ids 0-3 are raw resources.
+        // These are ItemId values, not CraftableItem ids (see `item`).
+        const PLANK: u8 = ItemId::Plank as u8;
+        const BRICK: u8 = ItemId::Brick as u8;
+        const ROPE: u8 = ItemId::Rope as u8;
         match self {
             CraftableItem::WateringCanRefill => vec![(0, 3), (2, 2)],    // 3 wood + 2 fiber
             CraftableItem::Fertilizer => vec![(2, 5), (3, 3)],          // 5 fiber + 3 seeds
             CraftableItem::CompostBin => vec![(0, 10), (1, 5)],         // 10 wood + 5 stone
             CraftableItem::Scarecrow => vec![(0, 8), (2, 12)],          // 8 wood + 12 fiber
-            CraftableItem::Fence => vec![(0, 15), (1, 8)],              // 15 wood + 8 stone
-            CraftableItem::Sprinkler => vec![(0, 20), (1, 12), (2, 5)], // 20 wood + 12 stone + 5 fiber
+            CraftableItem::Fence => vec![(PLANK, 5), (ROPE, 2)],                // 5 planks + 2 rope
+            CraftableItem::Sprinkler => vec![(PLANK, 6), (BRICK, 4), (ROPE, 2)],  // 6 planks + 4 bricks + 2 rope
             CraftableItem::AdvancedTool => vec![(0, 5), (1, 3)],        // 5 wood + 3 stone
+            CraftableItem::Plank => vec![(0, 3)],                       // 3 wood
+            CraftableItem::Brick => vec![(1, 3), (0, 1)],               // 3 stone + 1 wood (fuel)
+            CraftableItem::Rope => vec![(2, 4)],                        // 4 fiber
+        }
+    }
+
+    /// Extra outputs besides the item itself: Vec<(ItemId, amount)>
+    pub fn get_byproducts(&self) -> Vec<(u8, u16)> {
+        match self {
+            CraftableItem::Rope => vec![(3, 1)],                        // 1 seed
+            _ => vec![],
+        }
+    }
+
+    /// Inventory item the recipe produces (refills act on the watering can instead).
+    /// This is the only mapping between CraftableItem and ItemId numbering.
+    pub fn item(&self) -> Option<ItemId> {
+        match self {
+            CraftableItem::WateringCanRefill => None,
+            CraftableItem::Fertilizer => Some(ItemId::Fertilizer),
+            CraftableItem::CompostBin => Some(ItemId::CompostBin),
+            CraftableItem::Scarecrow => Some(ItemId::Scarecrow),
+            CraftableItem::Fence => Some(ItemId::Fence),
+            CraftableItem::Sprinkler => Some(ItemId::Sprinkler),
+            CraftableItem::AdvancedTool => Some(ItemId::AdvancedTool),
+            CraftableItem::Plank => Some(ItemId::Plank),
+            CraftableItem::Brick => Some(ItemId::Brick),
+            CraftableItem::Rope => Some(ItemId::Rope),
         }
     }
 
+    /// Recipe that produces an inventory item, if any (inverse of `item`)
+    pub fn for_item(item_id: ItemId) -> Option<Self> {
+        Self::ALL.into_iter().find(|item| item.item() == Some(item_id))
+    }
+
     pub fn get_output_quantity(&self) -> u16 {
         match self {
             CraftableItem::WateringCanRefill => 1,
@@ -61,6 +109,9 @@ impl CraftableItem {
             CraftableItem::Fence => 1,
             CraftableItem::Sprinkler => 1,
             CraftableItem::AdvancedTool => 1,
+            CraftableItem::Plank => 2,
+            CraftableItem::Brick => 2,
+            CraftableItem::Rope => 1,
         }
     }
 
@@ -74,10 +125,105 @@ impl CraftableItem {
             CraftableItem::Fence => 2700,                // 45 minutes
             CraftableItem::Sprinkler => 7200,            // 2 hours
             CraftableItem::AdvancedTool => 5400,         // 1.5 hours
+            CraftableItem::Plank => 0,                   // Instant
+            CraftableItem::Brick => 600,                 // 10 minutes
+            CraftableItem::Rope => 0,                    // Instant
+        }
+    }
+
+    /// Launch level for this recipe. The registry copies it into `Recipe::required_level`,
+    /// which is what craft_item and unlock_recipe enforce and admins can change.
+    pub fn required_level(&self) -> u8 {
+        match self {
+            CraftableItem::WateringCanRefill => 1,
+            CraftableItem::Fertilizer => 1,
+            CraftableItem::Plank => 1,
+            CraftableItem::Rope => 1,
+            CraftableItem::CompostBin => 2,
+            CraftableItem::Brick => 3,
+            CraftableItem::Scarecrow => 3,
+            CraftableItem::Fence => 4,
+            CraftableItem::AdvancedTool => 5,
+            CraftableItem::Sprinkler => 6,
         }
     }
 
-    pub fn is_instant(&self) -> bool {
-        self.get_crafting_time() == 0
+    /// Launch prerequisites: (structure the player must own, crafts completed)
+    pub fn default_prerequisites(&self) -> (Option<CraftableItem>, u16) {
+        match self {
+            CraftableItem::WateringCanRefill => (None, 0),
+            CraftableItem::Fertilizer => (None, 0),
+            CraftableItem::CompostBin => (None, 0),
+            CraftableItem::Scarecrow => (None, 3),
+            CraftableItem::Fence => (None, 5),
+            CraftableItem::Sprinkler => (Some(CraftableItem::Fence), 10),
+            CraftableItem::AdvancedTool => (Some(CraftableItem::CompostBin), 5),
+            CraftableItem::Plank => (None, 0),
+            CraftableItem::Brick => (None, 0),
+            CraftableItem::Rope => (None, 0),
+        }
+    }
+
+    pub const ALL: [CraftableItem; 10] = [
+        CraftableItem::WateringCanRefill,
+        CraftableItem::Fertilizer,
+        CraftableItem::CompostBin,
+        CraftableItem::Scarecrow,
+        CraftableItem::Fence,
+        CraftableItem::Sprinkler,
+        CraftableItem::AdvancedTool,
+        CraftableItem::Plank,
+        CraftableItem::Brick,
+        CraftableItem::Rope,
+    ];
+
+    /// Permanent structures can be exported as standalone asset accounts.
+    /// Consumables (refills, fertilizer) and tools stay inside the player account,
+    /// since an asset has nowhere to carry a tool's wear.
+    pub fn is_exportable(&self) -> bool {
+        matches!(
+            self,
+            CraftableItem::CompostBin
+                | CraftableItem::Scarecrow
+                | CraftableItem::Fence
+                | CraftableItem::Sprinkler
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn item_mapping_round_trips() {
+        assert_eq!(CraftableItem::Plank as u8, 7);
+        assert_eq!(CraftableItem::Plank.item(), Some(ItemId::Plank));
+        assert_eq!(ItemId::Plank as u8, 4);
+
+        for item in CraftableItem::ALL {
+            assert_eq!(CraftableItem::from_u8(item as u8).unwrap(), item);
+            match item.item() {
+                Some(item_id) => assert_eq!(CraftableItem::for_item(item_id), Some(item)),
+                None => assert_eq!(item, CraftableItem::WateringCanRefill),
+            }
+        }
+        for raw in [ItemId::Wood, ItemId::Compost, ItemId::PremiumFertilizer, ItemId::CropWaste] {
+            assert_eq!(CraftableItem::for_item(raw), None);
+        }
+    }
+
+    #[test]
+    fn recipe_tables_reference_real_items() {
+        for item in CraftableItem::ALL {
+            for (item_id, amount) in item.get_recipe_inputs().into_iter().chain(item.get_byproducts()) {
+                assert!(ItemId::from_u8(item_id).is_ok());
+                assert!(amount > 0);
+            }
+        }
+        assert_eq!(
+            CraftableItem::Sprinkler.get_recipe_inputs(),
+            vec![(ItemId::Plank as u8, 6), (ItemId::Brick as u8, 4), (ItemId::Rope as u8, 2)]
+        );
     }
 }
@@ -0,0 +1,192 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::WORLD_NODE_CAPACITY_MULTIPLIER;
+use crate::state::resources::ResourceType;
+
+/// Kinds of gatherable nodes and the resource each one yields
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum NodeKind {
+    Tree = 0,        // Wood
+    Rock = 1,        // Stone
+    GrassPatch = 2,  // Fiber
+}
+
+impl NodeKind {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(NodeKind::Tree),
+            1 => Some(NodeKind::Rock),
+            2 => Some(NodeKind::GrassPatch),
+            _ => None,
+        }
+    }
+
+    pub fn resource(&self) -> ResourceType {
+        match self {
+            NodeKind::Tree => ResourceType::Wood,
+            NodeKind::Rock => ResourceType::Stone,
+            NodeKind::GrassPatch => ResourceType::Fiber,
+        }
+    }
+
+    /// Capacity of a node on a player's own land; shared world nodes hold more
+    pub fn base_capacity(&self) -> u16 {
+        match self {
+            NodeKind::Tree => 30,
+            NodeKind::Rock => 20,
+            NodeKind::GrassPatch => 40,
+        }
+    }
+
+    /// Capacity of a new node of this kind
+    pub fn capacity(&self, shared: bool) -> u16 {
+        if shared {
+            self.base_capacity().saturating_mul(WORLD_NODE_CAPACITY_MULTIPLIER)
+        } else {
+            self.base_capacity()
+        }
+    }
+
+    /// Seconds for one unit to regrow
+    pub fn regrowth_seconds(&self) -> i64 {
+        match self {
+            NodeKind::Tree => 1200,       // 20 minutes
+            NodeKind::Rock => 1800,       // 30 minutes
+            NodeKind::GrassPatch => 600,  // 10 minutes
+        }
+    }
+}
+
+/// A gatherable resource node (tree, rock, grass patch) with finite, regrowing capacity.
+/// `region` is the owning player, or `Pubkey::default()` for the shared world region.
+#[account]
+pub struct ResourceNode {
+    pub region: Pubkey,
+    pub node_id: u32,
+    pub kind: NodeKind,
+    pub capacity: u16,           // Most the node can hold
+    pub amount: u16,             // Currently gatherable
+    pub regrowth_seconds: i64,   // Seconds per unit regrown
+    pub last_regrowth: i64,      // Regrowth has been settled up to here
+    pub bump: u8,
+}
+
+impl ResourceNode {
+    pub const SPACE: usize = 8 // discriminator
+        + 32 // region
+        + 4 // node_id
+        + 1 // kind
+        + 2 // capacity
+        + 2 // amount
+        + 8 // regrowth_seconds
+        + 8 // last_regrowth
+        + 1; // bump
+
+    /// Set up a new node, full and regrowing from `current_time`
+    pub fn init(&mut self, region: Pubkey, node_id: u32, kind: NodeKind, current_time: i64, bump: u8) {
+        self.region = region;
+        self.node_id = node_id;
+        self.kind = kind;
+        self.capacity = kind.capacity(self.is_shared());
+        self.amount = self.capacity;
+        self.regrowth_seconds = kind.regrowth_seconds();
+        self.last_regrowth = current_time;
+        self.bump = bump;
+    }
+
+    pub fn is_shared(&self) -> bool {
+        self.region == Pubkey::default()
+    }
+
+    /// Whether `player` may gather here: anyone on shared nodes, only the owner otherwise
+    pub fn can_gather(&self, player: &Pubkey) -> bool {
+        self.is_shared() || self.region == *player
+    }
+
+    /// Settle regrowth up to `current_time`. A full node does not bank regrowth.
+    pub fn regrow(&mut self, current_time: i64) {
+        if self.amount >= self.capacity || self.regrowth_seconds <= 0 {
+            self.last_regrowth = current_time;
+            return;
+        }
+
+        let units = current_time.saturating_sub(self.last_regrowth).max(0) / self.regrowth_seconds;
+        if units == 0 {
+            return;
+        }
+
+        let room = (self.capacity - self.amount) as i64;
+        self.amount += units.min(room) as u16;
+        self.last_regrowth = if self.amount >= self.capacity {
+            current_time
+        } else {
+            self.last_regrowth + units * self.regrowth_seconds
+        };
+    }
+
+    /// Take up to `amount` from the node, returning what was actually taken.
+    /// Call `regrow` first so a full node starts regrowing from now.
+    pub fn deplete(&mut self, amount: u16) -> u16 {
+        let taken = amount.min(self.amount);
+        self.amount -= taken;
+        taken
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(region: Pubkey, kind: NodeKind) -> ResourceNode {
+        let zeroed = vec![0u8; ResourceNode::SPACE];
+        let mut node = ResourceNode::try_deserialize_unchecked(&mut zeroed.as_slice()).unwrap();
+        node.init(region, 7, kind, 1_000, 255);
+        node
+    }
+
+    #[test]
+    fn new_nodes_start_full() {
+        let owner = Pubkey::new_unique();
+        let private = node(owner, NodeKind::Tree);
+        assert_eq!((private.capacity, private.amount), (30, 30));
+        assert!(private.can_gather(&owner));
+        assert!(!private.can_gather(&Pubkey::new_unique()));
+
+        let shared = node(Pubkey::default(), NodeKind::Tree);
+        assert_eq!(shared.capacity, 30 * WORLD_NODE_CAPACITY_MULTIPLIER);
+        assert!(shared.can_gather(&Pubkey::new_unique()));
+    }
+
+    #[test]
+    fn deplete_takes_no_more_than_is_left() {
+        let mut rock = node(Pubkey::new_unique(), NodeKind::Rock);
+        assert_eq!(rock.deplete(15), 15);
+        assert_eq!(rock.deplete(15), 5);
+        assert_eq!(rock.deplete(1), 0);
+        assert_eq!(rock.amount, 0);
+    }
+
+    #[test]
+    fn regrowth_carries_partial_units_and_stops_at_capacity() {
+        let mut grass = node(Pubkey::new_unique(), NodeKind::GrassPatch);
+        let step = grass.regrowth_seconds;
+
+        // A full node banks nothing while it waits
+        grass.regrow(1_000 + 10 * step);
+        assert_eq!(grass.last_regrowth, 1_000 + 10 * step);
+        grass.deplete(10);
+
+        // 2.5 units of time: two units back, the half is kept
+        let start = grass.last_regrowth;
+        grass.regrow(start + 2 * step + step / 2);
+        assert_eq!(grass.amount, 32);
+        assert_eq!(grass.last_regrowth, start + 2 * step);
+        grass.regrow(start + 3 * step);
+        assert_eq!(grass.amount, 33);
+
+        grass.regrow(start + 100 * step);
+        assert_eq!(grass.amount, grass.capacity);
+        assert_eq!(grass.last_regrowth, start + 100 * step);
+    }
+}
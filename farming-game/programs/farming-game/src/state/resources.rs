@@ -21,12 +21,7 @@ impl ResourceType {
     }
 
     pub fn max_stack_size(&self) -> u16 {
-        match self {
-            ResourceType::Wood => 999,
-            ResourceType::Stone => 999,
-            ResourceType::Fiber => 500,
-            ResourceType::Seeds => 500,
-        }
+        crate::state::inventory::ItemId::from_resource(*self).max_stack_size()
     }
 
     pub fn max_gather_per_action(&self) -> u16 {
@@ -37,13 +32,4 @@ impl ResourceType {
             ResourceType::Seeds => 0, // Cannot manually gather seeds (from harvests only)
         }
     }
-
-    pub fn gather_cooldown_seconds(&self) -> i64 {
-        match self {
-            ResourceType::Wood => 3600,      // 1 hour
-            ResourceType::Stone => 3600,     // 1 hour
-            ResourceType::Fiber => 1800,     // 30 minutes
-            ResourceType::Seeds => 0,        // N/A
-        }
-    }
 }
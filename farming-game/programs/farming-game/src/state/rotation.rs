@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    REPEAT_CROP_PENALTY_PERCENT, REPEAT_CROP_STREAK, ROTATION_CYCLE_BONUS_PERCENT, ROTATION_HISTORY_LEN,
+};
+use crate::state::crop::{CropFamily, CropType};
+
+/// One past planting on a tile
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CropRecord {
+    pub crop_type: u8,
+    pub season: u8, // Season the crop was planted in (0-3)
+}
+
+/// Ring buffer of the last ROTATION_HISTORY_LEN harvests on a tile
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RotationHistory {
+    pub entries: [CropRecord; ROTATION_HISTORY_LEN],
+    pub head: u8, // Next slot to write
+    pub len: u8,  // Number of valid entries
+}
+
+impl RotationHistory {
+    pub const SPACE: usize = ROTATION_HISTORY_LEN * 2 // entries
+        + 1 // head
+        + 1; // len
+
+    pub fn push(&mut self, record: CropRecord) {
+        self.entries[self.head as usize] = record;
+        self.head = ((self.head as usize + 1) % ROTATION_HISTORY_LEN) as u8;
+        self.len = (self.len + 1).min(ROTATION_HISTORY_LEN as u8);
+    }
+
+    /// Entries from most recent to oldest
+    pub fn recent(&self) -> impl Iterator<Item = CropRecord> + '_ {
+        (0..self.len as usize).map(move |i| {
+            self.entries[(self.head as usize + ROTATION_HISTORY_LEN - 1 - i) % ROTATION_HISTORY_LEN]
+        })
+    }
+
+    /// Temporal rotation effect of the most recent harvest on this tile
+    pub fn rotation_effect(&self) -> RotationEffect {
+        let recent: Vec<CropRecord> = self.recent().collect();
+        let mut effect = RotationEffect::default();
+
+        // Consecutive plantings of the latest crop
+        if let Some(latest) = recent.first() {
+            effect.repeat_streak = recent
+                .iter()
+                .take_while(|r| r.crop_type == latest.crop_type)
+                .count() as u8;
+        }
+
+        // Oldest → newest must read legume, grain, root
+        let families: Vec<Option<CropFamily>> = recent
+            .iter()
+            .take(3)
+            .map(|r| CropType::from_u8(r.crop_type).map(|c| c.get_config().family))
+            .collect();
+        effect.cycle_completed = families
+            == [Some(CropFamily::Root), Some(CropFamily::Grain), Some(CropFamily::Legume)];
+
+        if effect.cycle_completed {
+            effect.yield_percent += ROTATION_CYCLE_BONUS_PERCENT;
+        }
+        if effect.repeat_streak as usize >= REPEAT_CROP_STREAK {
+            effect.yield_percent += REPEAT_CROP_PENALTY_PERCENT;
+        }
+
+        effect
+    }
+}
+
+/// Yield adjustment from a tile's planting history
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RotationEffect {
+    pub yield_percent: i16,
+    pub cycle_completed: bool, // Legume → grain → root just finished
+    pub repeat_streak: u8,     // Times the latest crop was planted back to back
+}
+
+impl RotationEffect {
+    pub fn yield_multiplier(&self) -> f32 {
+        1.0 + (self.yield_percent as f32) / 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(crops: &[u8]) -> RotationHistory {
+        let mut history = RotationHistory::default();
+        for crop in crops {
+            history.push(CropRecord { crop_type: *crop, season: 0 });
+        }
+        history
+    }
+
+    #[test]
+    fn test_ring_buffer_keeps_latest() {
+        let history = history(&[1, 2, 3, 4, 5, 1]);
+        let recent: Vec<u8> = history.recent().map(|r| r.crop_type).collect();
+        assert_eq!(recent, vec![1, 5, 4, 3]);
+    }
+
+    #[test]
+    fn test_rotation_cycle_and_repeat_penalty() {
+        // Lettuce (legume) → wheat (grain) → carrot (root)
+        let effect = history(&[5, 1, 4]).rotation_effect();
+        assert!(effect.cycle_completed);
+        assert_eq!(effect.yield_percent, ROTATION_CYCLE_BONUS_PERCENT);
+
+        // Wrong order earns nothing
+        assert_eq!(history(&[4, 1, 5]).rotation_effect().yield_percent, 0);
+
+        // Corn three times running is penalised
+        let effect = history(&[4, 3, 3, 3]).rotation_effect();
+        assert_eq!(effect.repeat_streak, 3);
+        assert_eq!(effect.yield_percent, REPEAT_CROP_PENALTY_PERCENT);
+    }
+}
@@ -1,12 +1,14 @@
 use anchor_lang::prelude::*;
 
 use crate::constants::{NUM_SEASONS, SEASON_LENGTHS};
+use crate::errors::FarmingError;
 
 #[account]
 pub struct SeasonState {
     pub current_season: u8,   // 0=Spring, 1=Summer, 2=Fall, 3=Winter
     pub days_passed: u32,
     pub season_start_day: u32,
+    pub year: u32,            // Full Spring→Winter cycles completed
     pub authority: Pubkey,
     pub bump: u8,
 }
@@ -16,9 +18,58 @@ impl SeasonState {
         + 1 // current_season
         + 4 // days_passed
         + 4 // season_start_day
+        + 4 // year
         + 32 // authority
         + 1; // bump
 
+    /// Size before `year` was added
+    pub const LEGACY_SPACE: usize = Self::SPACE - 4;
+
+    /// Decode the account in either layout; the pre-`year` layout starts at year 0
+    pub fn from_versioned_bytes(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() >= 8 && data[..8] == *Self::DISCRIMINATOR,
+            FarmingError::UnsupportedSeasonLayout
+        );
+        match data.len() {
+            Self::SPACE => Ok(Self::try_deserialize(&mut &data[..])?),
+            Self::LEGACY_SPACE => {
+                let mut fields = &data[8..];
+                let current_season = u8::deserialize(&mut fields)?;
+                let days_passed = u32::deserialize(&mut fields)?;
+                let season_start_day = u32::deserialize(&mut fields)?;
+                let authority = Pubkey::deserialize(&mut fields)?;
+                let bump = u8::deserialize(&mut fields)?;
+                Ok(Self {
+                    current_season,
+                    days_passed,
+                    season_start_day,
+                    year: 0,
+                    authority,
+                    bump,
+                })
+            }
+            _ => err!(FarmingError::UnsupportedSeasonLayout),
+        }
+    }
+
+    /// Season and year that follow the current one
+    pub fn next_season(&self) -> (u8, u32) {
+        let season = (self.current_season + 1) % NUM_SEASONS;
+        let year = if season == 0 { self.year.saturating_add(1) } else { self.year };
+        (season, year)
+    }
+
+    /// Season and year a manual set_season jumps to: a season at or before the
+    /// current one can only come around again next year
+    pub fn set_season_target(&self, season: u8) -> (u8, u32) {
+        if season <= self.current_season {
+            (season, self.year.saturating_add(1))
+        } else {
+            (season, self.year)
+        }
+    }
+
     pub fn season_length(&self) -> u32 {
         let idx = self.current_season.min(NUM_SEASONS - 1) as usize;
         SEASON_LENGTHS[idx]
@@ -59,3 +110,59 @@ impl Season {
         SEASON_LENGTHS[idx]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(current_season: u8, year: u32) -> SeasonState {
+        SeasonState {
+            current_season,
+            days_passed: 40,
+            season_start_day: 30,
+            year,
+            authority: Pubkey::new_unique(),
+            bump: 253,
+        }
+    }
+
+    #[test]
+    fn winter_rolls_into_the_next_year() {
+        assert_eq!(state(0, 3).next_season(), (1, 3));
+        assert_eq!(state(NUM_SEASONS - 1, 3).next_season(), (0, 4));
+    }
+
+    #[test]
+    fn set_season_wraps_into_the_next_year() {
+        assert_eq!(state(1, 3).set_season_target(3), (3, 3));
+        assert_eq!(state(1, 3).set_season_target(1), (1, 4));
+        assert_eq!(state(2, 3).set_season_target(0), (0, 4));
+    }
+
+    #[test]
+    fn legacy_layout_decodes_at_year_zero() {
+        let current = state(2, 0);
+        let mut data = SeasonState::DISCRIMINATOR.to_vec();
+        data.push(current.current_season);
+        data.extend_from_slice(&current.days_passed.to_le_bytes());
+        data.extend_from_slice(&current.season_start_day.to_le_bytes());
+        data.extend_from_slice(current.authority.as_ref());
+        data.push(current.bump);
+        assert_eq!(data.len(), SeasonState::LEGACY_SPACE);
+
+        let decoded = SeasonState::from_versioned_bytes(&data).unwrap();
+        assert_eq!(decoded.current_season, 2);
+        assert_eq!(decoded.days_passed, 40);
+        assert_eq!(decoded.season_start_day, 30);
+        assert_eq!(decoded.year, 0);
+        assert_eq!(decoded.authority, current.authority);
+        assert_eq!(decoded.bump, 253);
+
+        let mut bytes = Vec::new();
+        decoded.try_serialize(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), SeasonState::SPACE);
+        assert_eq!(SeasonState::from_versioned_bytes(&bytes).unwrap().bump, 253);
+
+        assert!(SeasonState::from_versioned_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+}
@@ -0,0 +1,334 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    DEFAULT_SOIL_MOISTURE_RETENTION, DEFAULT_SOIL_NUTRIENT, FERTILIZER_BURN_THRESHOLD, FERTILIZER_BURN_WINDOW,
+    MAX_SOIL_LEVEL,
+};
+use crate::state::tools::{ToolConfig, ToolType};
+
+/// Per-tile soil profile; every value is a 0-100 level
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SoilProfile {
+    pub nitrogen: u8,            // Leaf growth; drained by grains
+    pub phosphorus: u8,          // Roots and fruit set
+    pub potassium: u8,           // Overall hardiness
+    pub moisture_retention: u8,  // How slowly the plot dries out (organic matter)
+}
+
+impl SoilProfile {
+    pub const SPACE: usize = 4;
+
+    /// Soil of a freshly created tile
+    pub const fn initial() -> Self {
+        Self {
+            nitrogen: DEFAULT_SOIL_NUTRIENT,
+            phosphorus: DEFAULT_SOIL_NUTRIENT,
+            potassium: DEFAULT_SOIL_NUTRIENT,
+            moisture_retention: DEFAULT_SOIL_MOISTURE_RETENTION,
+        }
+    }
+
+    /// Tiles created before soil profiles existed have an all-zero profile
+    pub fn is_unset(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Remove what a harvested crop took out of the ground
+    pub fn deplete(&mut self, demand: &NutrientDemand) {
+        self.nitrogen = self.nitrogen.saturating_sub(demand.nitrogen / 2);
+        self.phosphorus = self.phosphorus.saturating_sub(demand.phosphorus / 2);
+        self.potassium = self.potassium.saturating_sub(demand.potassium / 2);
+    }
+
+    /// Natural recovery while fallow: each nutrient regains `points`, but only up to the
+    /// starting level. Anything above that still has to come from fertilizer.
+    pub fn regenerate(&mut self, points: u8) {
+        for level in [&mut self.nitrogen, &mut self.phosphorus, &mut self.potassium] {
+            if *level < DEFAULT_SOIL_NUTRIENT {
+                *level = level.saturating_add(points).min(DEFAULT_SOIL_NUTRIENT);
+            }
+        }
+    }
+
+    /// Apply a fertilizer's nutrient changes
+    pub fn apply(&mut self, effect: &SoilEffect) {
+        self.nitrogen = add_level(self.nitrogen, effect.nitrogen);
+        self.phosphorus = add_level(self.phosphorus, effect.phosphorus);
+        self.potassium = add_level(self.potassium, effect.potassium);
+        self.moisture_retention = add_level(self.moisture_retention, effect.moisture_retention);
+    }
+
+    /// How well this soil covers a crop's demand, as a percentage (0-100).
+    /// Each nutrient is weighted by how much the crop needs it.
+    pub fn nutrient_coverage(&self, demand: &NutrientDemand) -> u32 {
+        let pairs = [
+            (self.nitrogen, demand.nitrogen),
+            (self.phosphorus, demand.phosphorus),
+            (self.potassium, demand.potassium),
+        ];
+
+        let total_demand: u32 = pairs.iter().map(|(_, d)| *d as u32).sum();
+        if total_demand == 0 {
+            return 100;
+        }
+
+        let covered: u32 = pairs
+            .iter()
+            .map(|(level, d)| (*level as u32).min(*d as u32))
+            .sum();
+        covered * 100 / total_demand
+    }
+}
+
+fn add_level(level: u8, delta: i8) -> u8 {
+    (level as i16 + delta as i16).clamp(0, MAX_SOIL_LEVEL as i16) as u8
+}
+
+/// Nutrient levels a crop needs for a full harvest
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NutrientDemand {
+    pub nitrogen: u8,
+    pub phosphorus: u8,
+    pub potassium: u8,
+}
+
+/// Permanent soil change caused by one application of a fertilizer
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SoilEffect {
+    pub fertility: u8,
+    pub nitrogen: i8,
+    pub phosphorus: i8,
+    pub potassium: i8,
+    pub moisture_retention: i8,
+}
+
+/// Fertilizer kinds, their source and how hard they hit the soil
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum FertilizerType {
+    Compost = 0,   // Basic, from compost bins: gentle and long-lasting, builds organic matter
+    Crafted = 1,   // Crafted from fiber and seeds, or bought as shop fertilizer: balanced N-P-K
+    Premium = 2,   // Bought from the shop as premium fertilizer: strongest boost, dries the soil and burns easily
+}
+
+/// Per-kind fertilizer stats
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FertilizerConfig {
+    pub fertility_boost: u8,  // Temporary fertility added while the boost lasts
+    pub duration: i64,        // Seconds the boost lasts
+    pub burn_load: u8,        // Load added toward the burn threshold per application
+    pub soil: SoilEffect,     // Permanent side effects on the soil
+}
+
+impl FertilizerType {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(FertilizerType::Compost),
+            1 => Some(FertilizerType::Crafted),
+            2 => Some(FertilizerType::Premium),
+            _ => None,
+        }
+    }
+
+    pub fn get_config(&self) -> FertilizerConfig {
+        match self {
+            FertilizerType::Compost => FertilizerConfig {
+                fertility_boost: 10,
+                duration: 3 * 86400,
+                burn_load: 1,
+                soil: SoilEffect {
+                    fertility: 5,
+                    nitrogen: 8,
+                    phosphorus: 8,
+                    potassium: 8,
+                    moisture_retention: 10,
+                },
+            },
+            FertilizerType::Crafted => FertilizerConfig {
+                fertility_boost: ToolConfig::get_config(ToolType::Fertilizer).fertility_boost,
+                duration: 86400,
+                burn_load: 2,
+                soil: SoilEffect {
+                    fertility: 0,
+                    nitrogen: 25,
+                    phosphorus: 15,
+                    potassium: 15,
+                    moisture_retention: -5,
+                },
+            },
+            FertilizerType::Premium => FertilizerConfig {
+                fertility_boost: ToolConfig::get_config(ToolType::PremiumFertilizer).fertility_boost,
+                duration: 2 * 86400,
+                burn_load: 3,
+                soil: SoilEffect {
+                    fertility: 0,
+                    nitrogen: 30,
+                    phosphorus: 25,
+                    potassium: 25,
+                    moisture_retention: -10,
+                },
+            },
+        }
+    }
+}
+
+/// Active fertilizer on a tile: the temporary boost and the burn bookkeeping
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FertilizerState {
+    pub boost: u8,            // Temporary fertility currently applied
+    pub boost_until: i64,     // When the boost wears off
+    pub load: u8,             // Burn load accumulated in the current window
+    pub window_start: i64,    // Start of the current burn window
+    pub burned: bool,         // Over-fertilized: yield penalty at the next harvest
+}
+
+impl FertilizerState {
+    pub const SPACE: usize = 1 // boost
+        + 8 // boost_until
+        + 1 // load
+        + 8 // window_start
+        + 1; // burned
+
+    /// Boost still in effect at `current_time`
+    pub fn active_boost(&self, current_time: i64) -> u8 {
+        if current_time < self.boost_until {
+            self.boost
+        } else {
+            0
+        }
+    }
+
+    /// Record one application. The strongest active boost wins and the duration
+    /// restarts; going over the burn threshold within the window burns the tile.
+    /// Returns true if this application burned the tile.
+    pub fn apply(&mut self, config: &FertilizerConfig, current_time: i64) -> bool {
+        if current_time.saturating_sub(self.window_start) >= FERTILIZER_BURN_WINDOW {
+            self.window_start = current_time;
+            self.load = 0;
+        }
+        self.load = self.load.saturating_add(config.burn_load);
+
+        self.boost = self.active_boost(current_time).max(config.fertility_boost);
+        self.boost_until = current_time.saturating_add(config.duration);
+
+        let burned_now = !self.burned && self.load > FERTILIZER_BURN_THRESHOLD;
+        if burned_now {
+            self.burned = true;
+        }
+        burned_now
+    }
+
+    /// Clear burn state after a harvest has paid the penalty
+    pub fn reset_burn(&mut self) {
+        self.burned = false;
+        self.load = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WHEAT_LIKE: NutrientDemand = NutrientDemand { nitrogen: 60, phosphorus: 30, potassium: 30 };
+
+    #[test]
+    fn harvest_drains_half_the_demand() {
+        let mut soil = SoilProfile::initial();
+        soil.deplete(&WHEAT_LIKE);
+        assert_eq!((soil.nitrogen, soil.phosphorus, soil.potassium), (30, 45, 45));
+
+        soil.deplete(&WHEAT_LIKE);
+        soil.deplete(&WHEAT_LIKE);
+        assert_eq!(soil.nitrogen, 0);
+    }
+
+    #[test]
+    fn coverage_is_weighted_by_demand() {
+        let rich = SoilProfile::initial();
+        assert_eq!(rich.nutrient_coverage(&WHEAT_LIKE), 100);
+
+        // Nitrogen at half of what is needed: 30 of 60, the rest fully covered
+        let soil = SoilProfile { nitrogen: 30, ..rich };
+        assert_eq!(soil.nutrient_coverage(&WHEAT_LIKE), (30 + 30 + 30) * 100 / 120);
+
+        let barren = SoilProfile { nitrogen: 0, phosphorus: 0, potassium: 0, ..rich };
+        assert_eq!(barren.nutrient_coverage(&WHEAT_LIKE), 0);
+        assert_eq!(barren.nutrient_coverage(&NutrientDemand { nitrogen: 0, phosphorus: 0, potassium: 0 }), 100);
+    }
+
+    #[test]
+    fn fertilizer_effects_clamp_to_soil_range() {
+        let mut soil = SoilProfile { nitrogen: 95, moisture_retention: 5, ..SoilProfile::initial() };
+        soil.apply(&FertilizerType::Premium.get_config().soil);
+        assert_eq!(soil.nitrogen, MAX_SOIL_LEVEL);
+        assert_eq!(soil.moisture_retention, 0);
+
+        soil.apply(&FertilizerType::Compost.get_config().soil);
+        assert_eq!(soil.moisture_retention, 10);
+    }
+
+    #[test]
+    fn burn_needs_load_above_the_threshold() {
+        let premium = FertilizerType::Premium.get_config();
+        let compost = FertilizerType::Compost.get_config();
+        let mut state = FertilizerState::default();
+
+        assert!(!state.apply(&premium, 1_000));
+        assert!(!state.apply(&compost, 1_001));
+        assert_eq!(state.load, FERTILIZER_BURN_THRESHOLD);
+        assert!(!state.burned);
+
+        assert!(state.apply(&compost, 1_002));
+        assert!(state.burned);
+    }
+
+    #[test]
+    fn burn_window_expires_after_a_day() {
+        let premium = FertilizerType::Premium.get_config();
+        let mut state = FertilizerState::default();
+
+        assert!(!state.apply(&premium, 1_000));
+        // Exactly one window later the load starts over
+        assert!(!state.apply(&premium, 1_000 + FERTILIZER_BURN_WINDOW));
+        assert_eq!(state.load, premium.burn_load);
+        assert_eq!(state.window_start, 1_000 + FERTILIZER_BURN_WINDOW);
+
+        assert!(state.apply(&premium, 1_000 + FERTILIZER_BURN_WINDOW + 1));
+    }
+
+    #[test]
+    fn burn_penalty_is_reported_once_until_reset() {
+        let premium = FertilizerType::Premium.get_config();
+        let mut state = FertilizerState::default();
+        state.apply(&premium, 1_000);
+        assert!(state.apply(&premium, 1_001));
+        assert!(!state.apply(&premium, 1_002));
+        assert!(state.burned);
+
+        state.reset_burn();
+        assert!(!state.burned);
+        assert_eq!(state.load, 0);
+        assert!(!state.apply(&premium, 1_003));
+    }
+
+    #[test]
+    fn strongest_active_boost_wins() {
+        let premium = FertilizerType::Premium.get_config();
+        let compost = FertilizerType::Compost.get_config();
+        let mut state = FertilizerState::default();
+
+        state.apply(&premium, 1_000);
+        state.apply(&compost, 1_001);
+        assert_eq!(state.active_boost(1_002), premium.fertility_boost);
+        assert_eq!(state.boost_until, 1_001 + compost.duration);
+
+        assert_eq!(state.active_boost(state.boost_until), 0);
+    }
+
+    #[test]
+    fn fallow_regeneration_restores_up_to_the_starting_level() {
+        let mut soil = SoilProfile { nitrogen: 10, phosphorus: 58, potassium: 90, ..SoilProfile::initial() };
+        soil.regenerate(5);
+        assert_eq!((soil.nitrogen, soil.phosphorus, soil.potassium), (15, DEFAULT_SOIL_NUTRIENT, 90));
+    }
+}
@@ -1,4 +1,11 @@
 use anchor_lang::prelude::*;
+use crate::constants::{
+    BPS_DENOMINATOR, CHECKERBOARD_BONUS, COMPANION_MAX_BONUS_PERCENT, COMPANION_MAX_PENALTY_PERCENT,
+    COMPANION_MAX_WATER_BONUS, COMPANION_WHEAT_CARROT_BONUS, CROP_DIVERSITY_BONUS, CROSS_PATTERN_BONUS,
+    DEFAULT_PATTERN_DIMINISHING_BPS, DEFAULT_PATTERN_YIELD_CAP_BPS, DIAGONAL_LINE_BONUS, FULL_BLOCK_BONUS,
+    FULL_LINE_BONUS, MIRROR_SYMMETRY_BONUS, MONOCULTURE_BLOCK_BONUS, MONOCULTURE_ROW_BONUS, PERIMETER_DEFENSE_BONUS,
+    RING_BONUS, ROTATION_SEQUENCE_BONUS,
+};
 use crate::state::crop::CropType;
 
 /// Pattern types that can be detected on the farm grid
@@ -13,9 +20,18 @@ pub enum PatternType {
     Checkerboard = 5,        // Alternating crops in 3x3 area
     PerimeterDefense = 6,    // Different crops forming border around center
     RotationSequence = 7,    // 4 different crops in a line
+    DiagonalLine = 8,        // 3+ same crop along a diagonal
+    FullBlock = 9,           // 3x3 square of same crop
+    Ring = 10,               // Hollow 3x3: 8 same crops around a different or empty center
+    FullLine = 11,           // Entire row or column of the grid is one crop
+    MirrorSymmetry = 12,     // Whole farm planted and mirrored left-right or top-bottom
 }
 
 impl PatternType {
+    pub const COUNT: usize = 13;
+    /// The eight launch patterns, MonocultureRow through RotationSequence
+    pub const CLASSIC_COUNT: usize = 8;
+
     /// Convert u8 to PatternType
     pub fn from_u8(value: u8) -> Result<Self> {
         match value {
@@ -27,6 +43,11 @@ impl PatternType {
             5 => Ok(PatternType::Checkerboard),
             6 => Ok(PatternType::PerimeterDefense),
             7 => Ok(PatternType::RotationSequence),
+            8 => Ok(PatternType::DiagonalLine),
+            9 => Ok(PatternType::FullBlock),
+            10 => Ok(PatternType::Ring),
+            11 => Ok(PatternType::FullLine),
+            12 => Ok(PatternType::MirrorSymmetry),
             _ => Err(error!(crate::errors::FarmingError::InvalidPatternType)),
         }
     }
@@ -42,10 +63,33 @@ impl PatternType {
             PatternType::Checkerboard => "Alternating crops in 3x3: +10% yield, pest resistance",
             PatternType::PerimeterDefense => "Border around center crop: +40% yield, disease immunity",
             PatternType::RotationSequence => "4 different crops in line: +20% yield, +10 fertility",
+            PatternType::DiagonalLine => "3+ same crops on a diagonal: +15% yield",
+            PatternType::FullBlock => "3x3 block of same crop: +35% yield",
+            PatternType::Ring => "Hollow 3x3 ring of same crop: +25% yield, +3 water",
+            PatternType::FullLine => "Whole row or column of one crop: +25% yield, +2 fiber",
+            PatternType::MirrorSymmetry => "Symmetric farm layout: +15% yield, +10 points",
         }
     }
 }
 
+/// A detected pattern together with the tiles that formed it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PatternMatch {
+    pub pattern: PatternType,
+    pub anchor: u8,   // Tile index the pattern was detected from
+    pub tiles: u64,   // Bit i set = tile i is part of the pattern (grids up to 8x8)
+}
+
+impl PatternMatch {
+    pub fn contains(&self, tile_index: u8) -> bool {
+        tile_index < 64 && self.tiles & (1u64 << tile_index) != 0
+    }
+
+    pub fn tile_count(&self) -> u32 {
+        self.tiles.count_ones()
+    }
+}
+
 /// Bonus structure for a detected pattern
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct PatternBonus {
@@ -69,31 +113,31 @@ impl PatternType {
     pub fn get_bonus(&self) -> PatternBonus {
         match self {
             PatternType::MonocultureRow => PatternBonus {
-                yield_multiplier: 1.15,
+                yield_multiplier: MONOCULTURE_ROW_BONUS,
                 fertility_bonus: 0,
                 water_bonus: 0,
                 resource_bonus: ResourceBonus::default(),
             },
             PatternType::MonocultureBlock => PatternBonus {
-                yield_multiplier: 1.20,
+                yield_multiplier: MONOCULTURE_BLOCK_BONUS,
                 fertility_bonus: 0,
                 water_bonus: 0,
                 resource_bonus: ResourceBonus::default(),
             },
             PatternType::CompanionPlanting => PatternBonus {
-                yield_multiplier: 1.10,
+                yield_multiplier: COMPANION_WHEAT_CARROT_BONUS,
                 fertility_bonus: 0,
                 water_bonus: 0,
                 resource_bonus: ResourceBonus::default(),
             },
             PatternType::CropDiversity => PatternBonus {
-                yield_multiplier: 1.25,
+                yield_multiplier: CROP_DIVERSITY_BONUS,
                 fertility_bonus: 5,
                 water_bonus: 0,
                 resource_bonus: ResourceBonus::default(),
             },
             PatternType::CrossPattern => PatternBonus {
-                yield_multiplier: 1.30,
+                yield_multiplier: CROSS_PATTERN_BONUS,
                 fertility_bonus: 0,
                 water_bonus: 0,
                 resource_bonus: ResourceBonus {
@@ -102,50 +146,214 @@ impl PatternType {
                 },
             },
             PatternType::Checkerboard => PatternBonus {
-                yield_multiplier: 1.10,
+                yield_multiplier: CHECKERBOARD_BONUS,
                 fertility_bonus: 0,
                 water_bonus: 2,
                 resource_bonus: ResourceBonus::default(),
             },
             PatternType::PerimeterDefense => PatternBonus {
-                yield_multiplier: 1.40,
+                yield_multiplier: PERIMETER_DEFENSE_BONUS,
                 fertility_bonus: 0,
                 water_bonus: 0,
                 resource_bonus: ResourceBonus::default(),
             },
             PatternType::RotationSequence => PatternBonus {
-                yield_multiplier: 1.20,
+                yield_multiplier: ROTATION_SEQUENCE_BONUS,
                 fertility_bonus: 10,
                 water_bonus: 0,
                 resource_bonus: ResourceBonus::default(),
             },
-        }
-    }
-}
-
-/// Get companion planting bonus for a pair of crops
-/// Returns Some(bonus) if the two crops have a beneficial pairing
-pub fn get_companion_bonus(crop_a: CropType, crop_b: CropType) -> Option<PatternBonus> {
-    match (crop_a, crop_b) {
-        // Wheat + Carrot: Classic companion planting
-        (CropType::Wheat, CropType::Carrot) | (CropType::Carrot, CropType::Wheat) => {
-            Some(PatternBonus {
-                yield_multiplier: 1.10,
+            PatternType::DiagonalLine => PatternBonus {
+                yield_multiplier: DIAGONAL_LINE_BONUS,
                 fertility_bonus: 0,
                 water_bonus: 0,
                 resource_bonus: ResourceBonus::default(),
-            })
-        }
-        // Corn + Lettuce: Corn provides shade, lettuce doesn't compete for water
-        (CropType::Corn, CropType::Lettuce) | (CropType::Lettuce, CropType::Corn) => {
-            Some(PatternBonus {
-                yield_multiplier: 1.05,
+            },
+            PatternType::FullBlock => PatternBonus {
+                yield_multiplier: FULL_BLOCK_BONUS,
                 fertility_bonus: 0,
-                water_bonus: 5,  // Water efficiency bonus
+                water_bonus: 0,
                 resource_bonus: ResourceBonus::default(),
-            })
+            },
+            PatternType::Ring => PatternBonus {
+                yield_multiplier: RING_BONUS,
+                fertility_bonus: 0,
+                water_bonus: 3,
+                resource_bonus: ResourceBonus::default(),
+            },
+            PatternType::FullLine => PatternBonus {
+                yield_multiplier: FULL_LINE_BONUS,
+                fertility_bonus: 0,
+                water_bonus: 0,
+                resource_bonus: ResourceBonus {
+                    fiber: 2,
+                    ..Default::default()
+                },
+            },
+            PatternType::MirrorSymmetry => PatternBonus {
+                yield_multiplier: MIRROR_SYMMETRY_BONUS,
+                fertility_bonus: 0,
+                water_bonus: 0,
+                resource_bonus: ResourceBonus {
+                    points: 10,
+                    ..Default::default()
+                },
+            },
+        }
+    }
+}
+
+/// Combined companion effect of all four cardinal neighbours on one crop
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CompanionEffect {
+    pub yield_percent: i16,        // Net yield change, clamped to the companion caps
+    pub water_bonus: u8,           // Net water bonus, capped
+    pub beneficial_neighbors: u8,  // Neighbours with a positive pairing
+    pub antagonist_neighbors: u8,  // Neighbours with a negative pairing
+}
+
+impl CompanionEffect {
+    /// Stack the pairings of `crop` with each of its neighbours, then apply the caps
+    pub fn from_neighbors(crop: CropType, neighbors: &[CropType]) -> Self {
+        let mut effect = CompanionEffect::default();
+
+        for neighbor in neighbors {
+            let config = crop.get_config();
+            let yield_percent = config.companion_yield[*neighbor as usize] as i16;
+            let water_bonus = config.companion_water[*neighbor as usize];
+
+            if yield_percent > 0 {
+                effect.beneficial_neighbors += 1;
+            } else if yield_percent < 0 {
+                effect.antagonist_neighbors += 1;
+            }
+            effect.yield_percent += yield_percent;
+            effect.water_bonus = effect.water_bonus.saturating_add(water_bonus);
+        }
+
+        effect.yield_percent = effect
+            .yield_percent
+            .clamp(COMPANION_MAX_PENALTY_PERCENT, COMPANION_MAX_BONUS_PERCENT);
+        effect.water_bonus = effect.water_bonus.min(COMPANION_MAX_WATER_BONUS);
+        effect
+    }
+
+    pub fn yield_multiplier(&self) -> f32 {
+        1.0 + (self.yield_percent as f32) / 100.0
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.beneficial_neighbors > 0 || self.antagonist_neighbors > 0 || self.water_bonus > 0
+    }
+}
+
+/// Rules for combining several patterns detected on the same tile
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StackingRules {
+    /// Upper bound on the combined pattern yield multiplier for a single tile
+    pub max_yield_multiplier: f32,
+    /// Patterns are applied strongest first; the n-th one keeps factor^n of its yield bonus
+    pub diminishing_factor: f32,
+    /// Only the strongest pattern of each group counts (e.g. a row inside a block)
+    pub exclusive_groups: &'static [&'static [PatternType]],
+}
+
+/// Monoculture shapes overlap heavily, as do the "different neighbours" shapes
+pub const EXCLUSIVE_PATTERN_GROUPS: &[&[PatternType]] = &[
+    &[
+        PatternType::MonocultureRow,
+        PatternType::MonocultureBlock,
+        PatternType::CrossPattern,
+        PatternType::DiagonalLine,
+        PatternType::FullBlock,
+        PatternType::Ring,
+        PatternType::FullLine,
+    ],
+    &[PatternType::CropDiversity, PatternType::PerimeterDefense],
+];
+
+impl Default for StackingRules {
+    fn default() -> Self {
+        Self::from_bps(DEFAULT_PATTERN_YIELD_CAP_BPS, DEFAULT_PATTERN_DIMINISHING_BPS)
+    }
+}
+
+/// Result of stacking every pattern and companion effect on one tile
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StackedBonus {
+    pub yield_multiplier: f32,
+    pub resource_bonus: ResourceBonus,
+    pub applied_patterns: u8,  // Patterns left after exclusive groups were resolved
+}
+
+impl StackingRules {
+    /// Rules with the cap and diminishing factor given in basis points, as stored in GameConfig
+    pub fn from_bps(yield_cap_bps: u16, diminishing_bps: u16) -> Self {
+        Self {
+            max_yield_multiplier: yield_cap_bps as f32 / BPS_DENOMINATOR as f32,
+            diminishing_factor: diminishing_bps as f32 / BPS_DENOMINATOR as f32,
+            exclusive_groups: EXCLUSIVE_PATTERN_GROUPS,
+        }
+    }
+
+    /// Drop patterns beaten by a stronger member of the same exclusive group
+    fn resolve_exclusive(&self, patterns: &[PatternType]) -> Vec<PatternType> {
+        let mut kept: Vec<PatternType> = Vec::new();
+
+        for pattern in patterns {
+            if kept.contains(pattern) {
+                continue;
+            }
+            let group = self.exclusive_groups.iter().find(|g| g.contains(pattern));
+            match group.and_then(|g| kept.iter().position(|k| g.contains(k))) {
+                Some(idx) => {
+                    if pattern.get_bonus().yield_multiplier > kept[idx].get_bonus().yield_multiplier {
+                        kept[idx] = *pattern;
+                    }
+                }
+                None => kept.push(*pattern),
+            }
+        }
+
+        kept
+    }
+
+    /// Combine patterns and the tile's companion effect into one bonus.
+    /// Yield bonuses are sorted strongest first and diminished; companion effects
+    /// (which can be penalties) are applied afterwards, then the per-tile cap.
+    pub fn stack(&self, patterns: &[PatternType], companion: Option<CompanionEffect>) -> StackedBonus {
+        let mut kept = self.resolve_exclusive(patterns);
+        kept.sort_by(|a, b| {
+            b.get_bonus()
+                .yield_multiplier
+                .total_cmp(&a.get_bonus().yield_multiplier)
+        });
+
+        let mut stacked = StackedBonus {
+            yield_multiplier: 1.0,
+            resource_bonus: ResourceBonus::default(),
+            applied_patterns: kept.len() as u8,
+        };
+
+        let mut weight = 1.0f32;
+        for pattern in kept.iter() {
+            let bonus = pattern.get_bonus();
+            stacked.yield_multiplier += (bonus.yield_multiplier - 1.0) * weight;
+            weight *= self.diminishing_factor;
+
+            // Resource bonuses add up in full
+            stacked.resource_bonus.seeds = stacked.resource_bonus.seeds.saturating_add(bonus.resource_bonus.seeds);
+            stacked.resource_bonus.fiber = stacked.resource_bonus.fiber.saturating_add(bonus.resource_bonus.fiber);
+            stacked.resource_bonus.wood = stacked.resource_bonus.wood.saturating_add(bonus.resource_bonus.wood);
+            stacked.resource_bonus.points = stacked.resource_bonus.points.saturating_add(bonus.resource_bonus.points);
         }
-        _ => None,
+
+        if let Some(companion) = companion {
+            stacked.yield_multiplier *= companion.yield_multiplier();
+        }
+
+        stacked.yield_multiplier = stacked.yield_multiplier.min(self.max_yield_multiplier);
+        stacked
     }
 }
 
@@ -165,13 +373,155 @@ mod tests {
 
     #[test]
     fn test_companion_planting() {
-        let bonus = get_companion_bonus(CropType::Wheat, CropType::Carrot);
-        assert!(bonus.is_some());
+        let bonus = CompanionEffect::from_neighbors(CropType::Wheat, &[CropType::Carrot]);
+        assert!(bonus.is_active() && bonus.yield_multiplier() > 1.0);
+
+        let bonus = CompanionEffect::from_neighbors(CropType::Corn, &[CropType::Lettuce]);
+        assert!(bonus.is_active());
+
+        let no_bonus = CompanionEffect::from_neighbors(CropType::Wheat, &[CropType::Corn]);
+        assert!(!no_bonus.is_active());
+
+        let penalty = CompanionEffect::from_neighbors(CropType::Tomato, &[CropType::Corn]);
+        assert!(penalty.yield_multiplier() < 1.0);
+    }
 
-        let bonus = get_companion_bonus(CropType::Corn, CropType::Lettuce);
-        assert!(bonus.is_some());
+    #[test]
+    fn test_companion_effects_stack_with_cap() {
+        // Carrot surrounded by wheat and tomatoes: 4 × +10% stacks to 40%, capped at 20%
+        let effect = CompanionEffect::from_neighbors(
+            CropType::Carrot,
+            &[CropType::Wheat, CropType::Tomato, CropType::Wheat, CropType::Tomato],
+        );
+        assert_eq!(effect.beneficial_neighbors, 4);
+        assert_eq!(effect.yield_percent, COMPANION_MAX_BONUS_PERCENT);
+
+        // Tomato next to carrot (+10) and two corn (-10 each) nets -10%
+        let effect = CompanionEffect::from_neighbors(
+            CropType::Tomato,
+            &[CropType::Carrot, CropType::Corn, CropType::Corn],
+        );
+        assert_eq!(effect.beneficial_neighbors, 1);
+        assert_eq!(effect.antagonist_neighbors, 2);
+        assert_eq!(effect.yield_percent, -10);
+
+        // Corn + lettuce keeps its water bonus
+        let effect = CompanionEffect::from_neighbors(CropType::Corn, &[CropType::Lettuce]);
+        assert_eq!(effect.water_bonus, 5);
+    }
+
+    #[test]
+    fn test_exclusive_groups_keep_strongest() {
+        let rules = StackingRules::default();
+        let stacked = rules.stack(
+            &[PatternType::MonocultureRow, PatternType::MonocultureBlock, PatternType::CrossPattern],
+            None,
+        );
+        // Only the cross (+30%) survives the monoculture group
+        assert_eq!(stacked.applied_patterns, 1);
+        assert!((stacked.yield_multiplier - 1.30).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_diminishing_returns_and_cap() {
+        let rules = StackingRules::default();
+        // Perimeter +40%, then checkerboard +10% at half weight, rotation +20% at quarter weight
+        let stacked = rules.stack(
+            &[PatternType::Checkerboard, PatternType::PerimeterDefense, PatternType::RotationSequence],
+            None,
+        );
+        assert!((stacked.yield_multiplier - (1.0 + 0.40 + 0.20 * 0.5 + 0.10 * 0.25)).abs() < 1e-5);
+
+        let capped = rules.stack(
+            &[PatternType::PerimeterDefense, PatternType::RotationSequence, PatternType::CrossPattern],
+            Some(CompanionEffect { yield_percent: 20, ..Default::default() }),
+        );
+        assert_eq!(capped.yield_multiplier, rules.max_yield_multiplier);
+    }
+
+    #[test]
+    fn test_rules_follow_configured_bps() {
+        let patterns = [PatternType::PerimeterDefense, PatternType::Checkerboard];
 
-        let no_bonus = get_companion_bonus(CropType::Wheat, CropType::Corn);
-        assert!(no_bonus.is_none());
+        // No diminishing and a high cap: bonuses add up in full
+        let loose = StackingRules::from_bps(30_000, 10_000).stack(&patterns, None);
+        assert!((loose.yield_multiplier - 1.50).abs() < 1e-5);
+
+        // Later patterns count for nothing and the cap is tight
+        let strict = StackingRules::from_bps(13_000, 0).stack(&patterns, None);
+        assert!((strict.yield_multiplier - 1.30).abs() < 1e-5);
+    }
+
+    /// Simulate harvests on a few reference layouts and check the best tile of each
+    /// against the table in PATTERN_BALANCE.md.
+    #[test]
+    fn simulate_best_yield_per_layout() {
+        use crate::state::pattern_detector::PatternDetector;
+        use crate::state::player::FarmTile;
+
+        const W: u8 = 1; // Wheat
+        const T: u8 = 2; // Tomato
+        const C: u8 = 3; // Corn
+        const R: u8 = 4; // Carrot
+        const L: u8 = 5; // Lettuce
+
+        // (name, layout, documented stacked multiplier, documented unrestricted multiplier)
+        let layouts: [(&str, [u8; 25], f32, f32); 4] = [
+            ("all wheat", [W; 25], 1.35, 2.68),
+            ("wheat cross + carrots", [
+                R, W, W, W, R,
+                R, W, W, W, R,
+                R, W, W, W, R,
+                R, R, W, R, R,
+                R, R, R, R, R,
+            ], 1.60, 2.96),
+            ("perimeter ring", [
+                W, T, C, R, L,
+                T, C, R, L, W,
+                C, R, W, T, C,
+                R, L, T, C, R,
+                L, W, C, R, T,
+            ], 1.60, 1.85),
+            ("checkerboard", [
+                W, R, W, R, W,
+                R, W, R, W, R,
+                W, R, W, R, W,
+                R, W, R, W, R,
+                W, R, W, R, W,
+            ], 1.50, 1.75),
+        ];
+
+        let rules = StackingRules::default();
+        let current_time = 1_000_000;
+
+        for (name, crops, documented, documented_raw) in layouts.iter() {
+            let tiles: Vec<FarmTile> = crops
+                .iter()
+                .map(|crop| FarmTile { crop_type: *crop, ..Default::default() })
+                .collect();
+
+            let mut best = (1.0f32, 1.0f32, 0usize);
+            for idx in 0..25 {
+                let (row, col) = (idx / 5, idx % 5);
+                let patterns: Vec<PatternType> = PatternDetector::detect_patterns(&tiles, 5, row, col, current_time)
+                    .iter()
+                    .map(|m| m.pattern)
+                    .collect();
+                let companion = PatternDetector::check_companion_planting(&tiles, 5, row, col, current_time);
+
+                let stacked = rules.stack(&patterns, companion);
+                let raw = patterns.iter().map(|p| p.get_bonus().yield_multiplier).product::<f32>()
+                    * companion.map_or(1.0, |c| c.yield_multiplier());
+
+                assert!(stacked.yield_multiplier <= rules.max_yield_multiplier);
+                assert!(stacked.yield_multiplier <= raw.max(1.0) + 1e-6);
+                if stacked.yield_multiplier > best.0 {
+                    best = (stacked.yield_multiplier, raw, idx);
+                }
+            }
+
+            assert!((best.0 - documented).abs() < 0.005, "layout '{}': stacked {:.3}x", name, best.0);
+            assert!((best.1 - documented_raw).abs() < 0.005, "layout '{}': unrestricted {:.3}x", name, best.1);
+        }
     }
 }
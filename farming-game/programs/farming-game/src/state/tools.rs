@@ -1,8 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::TOOL_DURABILITY_PER_USE;
+use crate::state::resources::ResourceType;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ToolType {
     WateringCan = 0,
     Fertilizer = 1,
     PremiumSeeds = 2,
+    PremiumFertilizer = 3,
 }
 
 impl ToolType {
@@ -11,6 +17,7 @@ impl ToolType {
             0 => Some(ToolType::WateringCan),
             1 => Some(ToolType::Fertilizer),
             2 => Some(ToolType::PremiumSeeds),
+            3 => Some(ToolType::PremiumFertilizer),
             _ => None,
         }
     }
@@ -41,8 +48,165 @@ impl ToolConfig {
                 fertility_boost: 0,
                 cost_points: 15,       // Costs 15 points per premium seed
             },
+            ToolType::PremiumFertilizer => ToolConfig {
+                water_amount: 0,
+                fertility_boost: 30,   // Adds 30 fertility, but dries the soil and burns easily
+                cost_points: 25,       // Costs 25 points per premium fertilizer
+            },
+        }
+    }
+}
+
+/// Durable tools held by every player, one slot per kind
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ToolKind {
+    WateringCan = 0,
+    Axe = 1,
+    Pickaxe = 2,
+    Scythe = 3,
+}
+
+impl ToolKind {
+    pub const COUNT: usize = 4;
+
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(ToolKind::WateringCan),
+            1 => Some(ToolKind::Axe),
+            2 => Some(ToolKind::Pickaxe),
+            3 => Some(ToolKind::Scythe),
+            _ => None,
+        }
+    }
+
+    /// Tool used to gather a resource (seeds are never gathered by hand)
+    pub fn for_resource(resource: ResourceType) -> Option<Self> {
+        match resource {
+            ResourceType::Wood => Some(ToolKind::Axe),
+            ResourceType::Stone => Some(ToolKind::Pickaxe),
+            ResourceType::Fiber => Some(ToolKind::Scythe),
+            ResourceType::Seeds => None,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum ToolTier {
+    #[default]
+    Basic = 0,
+    Copper = 1,
+    Iron = 2,
+    Gold = 3,
+}
+
+impl ToolTier {
+    pub fn next(&self) -> Option<Self> {
+        match self {
+            ToolTier::Basic => Some(ToolTier::Copper),
+            ToolTier::Copper => Some(ToolTier::Iron),
+            ToolTier::Iron => Some(ToolTier::Gold),
+            ToolTier::Gold => None,
+        }
+    }
+
+    /// Player level needed to upgrade a tool to this tier. Fixed in code alongside the
+    /// tier's costs; only recipe gates are admin-tunable (see `Recipe::required_level`).
+    pub fn required_level(&self) -> u8 {
+        match self {
+            ToolTier::Basic => 1,
+            ToolTier::Copper => 3,
+            ToolTier::Iron => 6,
+            ToolTier::Gold => 10,
+        }
+    }
+
+    pub fn max_durability(&self) -> u16 {
+        match self {
+            ToolTier::Basic => 50,
+            ToolTier::Copper => 100,
+            ToolTier::Iron => 200,
+            ToolTier::Gold => 400,
+        }
+    }
+
+    /// Watering can uses per refill
+    pub fn can_capacity(&self) -> u8 {
+        match self {
+            ToolTier::Basic => 10,
+            ToolTier::Copper => 15,
+            ToolTier::Iron => 20,
+            ToolTier::Gold => 30,
+        }
+    }
+
+    /// Water added to a plot per use of the can
+    pub fn water_amount(&self) -> u8 {
+        match self {
+            ToolTier::Basic => ToolConfig::get_config(ToolType::WateringCan).water_amount,
+            ToolTier::Copper => 60,
+            ToolTier::Iron => 75,
+            ToolTier::Gold => 100,
+        }
+    }
+
+    /// Extra resources per gather action with an axe, pickaxe or scythe of this tier
+    pub fn gather_bonus_percent(&self) -> u16 {
+        match self {
+            ToolTier::Basic => 0,
+            ToolTier::Copper => 25,
+            ToolTier::Iron => 50,
+            ToolTier::Gold => 100,
+        }
+    }
+
+    /// Resources (type, amount) to craft an upgrade into this tier
+    pub fn upgrade_cost(&self) -> Vec<(u8, u16)> {
+        match self {
+            ToolTier::Basic => vec![],
+            ToolTier::Copper => vec![(0, 10), (1, 10)],            // 10 wood + 10 stone
+            ToolTier::Iron => vec![(0, 15), (1, 25)],              // 15 wood + 25 stone
+            ToolTier::Gold => vec![(0, 30), (1, 50), (2, 20)],     // 30 wood + 50 stone + 20 fiber
+        }
+    }
+
+    /// Resources (type, amount) to craft a full repair at this tier
+    pub fn repair_cost(&self) -> Vec<(u8, u16)> {
+        match self {
+            ToolTier::Basic => vec![(0, 2)],                       // 2 wood
+            ToolTier::Copper => vec![(0, 2), (1, 3)],              // 2 wood + 3 stone
+            ToolTier::Iron => vec![(0, 3), (1, 6)],                // 3 wood + 6 stone
+            ToolTier::Gold => vec![(0, 5), (1, 10), (2, 5)],       // 5 wood + 10 stone + 5 fiber
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Tool {
+    pub tier: ToolTier,
+    pub durability: u16,     // 0 = broken until repaired
+}
+
+impl Tool {
+    pub const SPACE: usize = 1 // tier
+        + 2; // durability
+
+    pub fn new(tier: ToolTier) -> Self {
+        Self {
+            tier,
+            durability: tier.max_durability(),
         }
     }
+
+    pub fn is_broken(&self) -> bool {
+        self.durability == 0
+    }
+
+    /// Wear the tool down by one use
+    pub fn wear(&mut self) {
+        self.durability = self.durability.saturating_sub(TOOL_DURABILITY_PER_USE);
+    }
 }
 
 /// Calculate water modifier based on soil moisture level
@@ -58,3 +222,37 @@ pub fn get_water_modifier(water_level: u8) -> f32 {
         _ => 0.5,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tiers_climb_to_gold() {
+        let mut tier = ToolTier::Basic;
+        let mut tiers = vec![tier];
+        while let Some(next) = tier.next() {
+            assert!(next.required_level() > tier.required_level());
+            assert!(next.max_durability() > tier.max_durability());
+            assert!(next.gather_bonus_percent() > tier.gather_bonus_percent());
+            tier = next;
+            tiers.push(tier);
+        }
+        assert_eq!(tiers, vec![ToolTier::Basic, ToolTier::Copper, ToolTier::Iron, ToolTier::Gold]);
+        assert!(ToolTier::Basic.upgrade_cost().is_empty());
+        assert_eq!(ToolTier::Gold.upgrade_cost(), vec![(0, 30), (1, 50), (2, 20)]);
+    }
+
+    #[test]
+    fn wear_breaks_the_tool_at_zero() {
+        let mut tool = Tool::new(ToolTier::Basic);
+        assert_eq!(tool.durability, ToolTier::Basic.max_durability());
+        for _ in 0..ToolTier::Basic.max_durability() {
+            assert!(!tool.is_broken());
+            tool.wear();
+        }
+        assert!(tool.is_broken());
+        tool.wear();
+        assert_eq!(tool.durability, 0);
+    }
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+use crate::state::inventory::{Inventory, ItemId};
+use crate::state::resources::ResourceType;
+
+/// Resources and coins on one side of a trade.
+/// Resources are indexed by ResourceType: [wood, stone, fiber, seeds]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TradeTerms {
+    pub resources: [u16; 4],
+    pub coins: u64,
+}
+
+impl TradeTerms {
+    pub const SPACE: usize = 8 // resources (4 × 2)
+        + 8; // coins
+
+    pub fn is_empty(&self) -> bool {
+        self.coins == 0 && self.resources.iter().all(|amount| *amount == 0)
+    }
+
+    /// Resource list in the (resource_type, amount) form used by PlayerAccount helpers
+    pub fn resource_list(&self) -> Vec<(u8, u16)> {
+        self.resources
+            .iter()
+            .enumerate()
+            .filter(|(_, amount)| **amount > 0)
+            .map(|(resource_type, amount)| (resource_type as u8, *amount))
+            .collect()
+    }
+
+    /// Pay these terms into an inventory and coin balance as far as stack limits allow.
+    /// Whatever does not fit stays in the terms for a later payout.
+    pub fn pay_out_capped(&mut self, inventory: &mut Inventory, coins: &mut u64) -> Result<()> {
+        for (resource_type, amount) in self.resources.iter_mut().enumerate() {
+            let item = ItemId::from_resource(ResourceType::from_u8(resource_type as u8)?);
+            *amount -= inventory.add_capped(item, *amount);
+        }
+        *coins = coins.saturating_add(self.coins);
+        self.coins = 0;
+        Ok(())
+    }
+}
+
+/// A one-to-one trade offer. The offered side is escrowed in this account
+/// (already removed from the maker's PlayerAccount) until it is accepted,
+/// cancelled or expires.
+#[account]
+pub struct TradeOffer {
+    pub maker: Pubkey,
+    pub taker: Option<Pubkey>,        // Restrict to one counterparty (None = open to anyone)
+    pub offer_id: u64,                // Maker-chosen id, unique per maker
+    pub offered: TradeTerms,          // Escrowed from the maker
+    pub requested: TradeTerms,        // Paid by the taker on accept
+    pub created_at: i64,
+    pub expires_at: i64,
+    pub cancelled: bool,              // Cancelled with part of the escrow still owed to the maker
+    pub bump: u8,
+}
+
+impl TradeOffer {
+    pub const SPACE: usize = 8 // discriminator
+        + 32 // maker
+        + 33 // taker (Option<Pubkey> = 1 tag + 32)
+        + 8 // offer_id
+        + TradeTerms::SPACE // offered
+        + TradeTerms::SPACE // requested
+        + 8 // created_at
+        + 8 // expires_at
+        + 1 // cancelled
+        + 1; // bump
+
+    pub fn is_expired(&self, current_time: i64) -> bool {
+        current_time >= self.expires_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn refund_keeps_what_does_not_fit() {
+        let mut inventory = Inventory::default();
+        let limit = ItemId::Wood.max_stack_size();
+        inventory.add(ItemId::Wood, limit - 3).unwrap();
+
+        let mut terms = TradeTerms { resources: [10, 4, 0, 0], coins: 50 };
+        let mut coins = 0;
+        terms.pay_out_capped(&mut inventory, &mut coins).unwrap();
+
+        assert_eq!(terms, TradeTerms { resources: [7, 0, 0, 0], coins: 0 });
+        assert_eq!(inventory.amount(ItemId::Wood), limit);
+        assert_eq!(inventory.amount(ItemId::Stone), 4);
+        assert_eq!(coins, 50);
+
+        inventory.remove(ItemId::Wood, 7).unwrap();
+        terms.pay_out_capped(&mut inventory, &mut coins).unwrap();
+        assert!(terms.is_empty());
+    }
+}
@@ -74,10 +74,11 @@ describe("farming-game", () => {
       // Verify defaults
       assert.equal(playerAccount.owner.toString(), player.toString());
       assert.equal(playerAccount.coins.toNumber(), 0);
-      assert.equal(playerAccount.farmTiles.length, 25);
+      assert.equal(playerAccount.farm.gridSize, 5);
+      assert.equal(playerAccount.farm.tiles.length, 25);
 
       // Verify all tiles are empty
-      playerAccount.farmTiles.forEach((tile, index) => {
+      playerAccount.farm.tiles.forEach((tile, index) => {
         assert.equal(tile.cropType, 0, `Tile ${index} should be empty`);
         assert.equal(tile.plantedAt.toNumber(), 0, `Tile ${index} planted_at should be 0`);
       });
@@ -85,7 +86,7 @@ describe("farming-game", () => {
       console.log("✅ Player account initialized successfully");
       console.log(`   Owner: ${playerAccount.owner}`);
       console.log(`   Coins: ${playerAccount.coins}`);
-      console.log(`   Tiles: ${playerAccount.farmTiles.length}`);
+      console.log(`   Grid: ${playerAccount.farm.gridSize}x${playerAccount.farm.gridSize}`);
     });
   });
 
@@ -105,7 +106,7 @@ describe("farming-game", () => {
 
       // Fetch updated account
       const playerAccount = await program.account.playerAccount.fetch(playerPDA);
-      const tile = playerAccount.farmTiles[tileIndex];
+      const tile = playerAccount.farm.tiles[tileIndex];
 
       // Verify tile was updated
       assert.equal(tile.cropType, CROP_WHEAT);
@@ -151,7 +152,7 @@ describe("farming-game", () => {
 
       // Fetch updated account
       const playerAccount = await program.account.playerAccount.fetch(playerPDA);
-      const tile = playerAccount.farmTiles[tileIndex];
+      const tile = playerAccount.farm.tiles[tileIndex];
 
       // Verify tile was updated
       assert.equal(tile.cropType, CROP_CORN);
@@ -208,7 +209,7 @@ describe("farming-game", () => {
 
       // Fetch updated account
       const afterAccount = await program.account.playerAccount.fetch(playerPDA);
-      const tile = afterAccount.farmTiles[tileIndex];
+      const tile = afterAccount.farm.tiles[tileIndex];
       const coinsAfter = afterAccount.coins.toNumber();
 
       // Verify tile was cleared
@@ -250,7 +251,7 @@ describe("farming-game", () => {
 
       // Fetch updated account
       const afterAccount = await program.account.playerAccount.fetch(playerPDA);
-      const tile = afterAccount.farmTiles[tileIndex];
+      const tile = afterAccount.farm.tiles[tileIndex];
       const coinsAfter = afterAccount.coins.toNumber();
 
       // Verify tile was cleared
@@ -356,4 +357,29 @@ describe("farming-game", () => {
       console.log(`   Total earned: ${finalCoins - initialCoins}`);
     });
   });
+
+  describe("expand_farm", () => {
+    it("Fails below the level required for a 6x6 grid (LevelTooLow)", async () => {
+      try {
+        await program.methods
+          .expandFarm()
+          .accounts({
+            playerAccount: playerPDA,
+            authority: player,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .rpc();
+
+        assert.fail("Should have thrown LevelTooLow error");
+      } catch (error) {
+        assert.include(error.toString(), "LevelTooLow");
+        console.log("✅ Correctly rejected expansion for a level 1 player");
+      }
+
+      // The grid keeps its size and tiles
+      const playerAccount = await program.account.playerAccount.fetch(playerPDA);
+      assert.equal(playerAccount.farm.gridSize, 5);
+      assert.equal(playerAccount.farm.tiles.length, 25);
+    });
+  });
 });